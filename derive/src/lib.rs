@@ -0,0 +1,224 @@
+//! Derive macros for [oxidizer](https://github.com/brunocodutra/oxidizer).
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Fields, Ident};
+use syn::{GenericParam, Generics, Index, Lifetime, LifetimeDef, Result, Token, Type};
+
+/// Implements `CustomWidget` for a struct, allowing it to be mounted into a widget tree.
+///
+/// Besides `CustomWidget`, this derives `Variant<Widget>`, field-wise `Eq` and `Hash` without
+/// bounds on type parameters, and conversions into `Widget`, which integrate the struct with the
+/// `widget!` macro.
+/// The struct must also implement `Debug`, `Clone` and, to be used with `widget!`, `Default`.
+///
+/// The type of action is the only type parameter of the struct, or else must be given by
+/// `#[widget(action = Type)]`.
+/// Fields marked with `#[widget(handler)]` are handlers that events are routed to,
+/// either `Handler` or `OptionalHandler`.
+#[proc_macro_derive(Widget, attributes(widget))]
+pub fn derive_widget(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => return Err(Error::new_spanned(&input, "expected a struct")),
+    };
+
+    let action = action(&input)?;
+    let name = &input.ident;
+    let label = name.to_string();
+
+    let (members, handlers) = members(&data.fields)?;
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let w = Lifetime::new("'__w", Span::call_site());
+    let a = Lifetime::new("'__a", Span::call_site());
+
+    let with_w = prepend(&input.generics, parse_quote!(#w));
+    let (impl_generics_w, _, _) = with_w.split_for_impl();
+
+    let mut with_a = prepend(&with_w, parse_quote!(#a: #w));
+    let (impl_generics_a, _, _) = with_a.split_for_impl();
+    let impl_generics_a = impl_generics_a.to_token_stream();
+
+    with_a.make_where_clause().predicates.push(parse_quote! {
+        #name #ty_generics: ::oxidizer::widget::CustomWidget<#action>
+    });
+
+    let (_, _, convertible) = with_a.split_for_impl();
+
+    let mut static_generics = input.generics.clone();
+    static_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(Self: 'static));
+
+    let (_, _, static_where_clause) = static_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics_w ::oxidizer::Variant<::oxidizer::Widget<#w, #action>>
+            for #name #ty_generics #where_clause {}
+
+        impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                true #(&& self.#members == other.#members)*
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Eq for #name #ty_generics #where_clause {}
+
+        impl #impl_generics ::core::hash::Hash for #name #ty_generics #where_clause {
+            fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                #(::core::hash::Hash::hash(&self.#members, state);)*
+            }
+        }
+
+        impl #impl_generics ::oxidizer::widget::CustomWidget<#action>
+            for #name #ty_generics #static_where_clause
+        {
+            fn name(&self) -> &'static str {
+                #label
+            }
+
+            fn handle(&self, event: &::oxidizer::Event) -> ::core::option::Option<#action> {
+                ::core::option::Option::None
+                    #(.or_else(|| ::oxidizer::HandleEvent::handle_event(&self.#handlers, self, event)))*
+            }
+        }
+
+        impl #impl_generics_w ::core::convert::From<#name #ty_generics>
+            for ::oxidizer::Widget<#w, #action> #convertible
+        {
+            fn from(widget: #name #ty_generics) -> Self {
+                ::oxidizer::widget::Custom::new(widget).into()
+            }
+        }
+
+        impl #impl_generics_a ::core::convert::From<&#a #name #ty_generics>
+            for ::oxidizer::Widget<#w, #action> #convertible
+        {
+            fn from(widget: &#a #name #ty_generics) -> Self {
+                ::oxidizer::widget::Custom::new(::core::clone::Clone::clone(widget)).into()
+            }
+        }
+    })
+}
+
+/// Adds `param` as the first generic parameter.
+fn prepend(generics: &Generics, param: LifetimeDef) -> Generics {
+    let mut generics = generics.clone();
+    generics.params.insert(0, GenericParam::Lifetime(param));
+    generics
+}
+
+/// An option within `#[widget(...)]` attributes.
+enum WidgetOption {
+    Action(Type),
+    Handler(Ident),
+}
+
+impl Parse for WidgetOption {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        if ident == "action" {
+            input.parse::<Token![=]>()?;
+            Ok(WidgetOption::Action(input.parse()?))
+        } else if ident == "handler" {
+            Ok(WidgetOption::Handler(ident))
+        } else {
+            Err(Error::new_spanned(ident, "expected `action` or `handler`"))
+        }
+    }
+}
+
+impl ToTokens for WidgetOption {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            WidgetOption::Action(t) => t.to_tokens(tokens),
+            WidgetOption::Handler(i) => i.to_tokens(tokens),
+        }
+    }
+}
+
+/// The options within `#[widget(...)]` attributes.
+fn options(attrs: &[Attribute]) -> Result<Vec<WidgetOption>> {
+    let mut options = Vec::new();
+
+    for attr in attrs.iter().filter(|a| a.path.is_ident("widget")) {
+        options
+            .extend(attr.parse_args_with(Punctuated::<WidgetOption, Token![,]>::parse_terminated)?);
+    }
+
+    Ok(options)
+}
+
+/// The type of action, either given explicitly or the only type parameter.
+fn action(input: &DeriveInput) -> Result<Type> {
+    let mut options = options(&input.attrs)?.into_iter();
+
+    if let Some(option) = options.next() {
+        if let Some(extra) = options.next() {
+            return Err(Error::new_spanned(
+                extra,
+                "expected a single `action = Type`",
+            ));
+        }
+
+        return match option {
+            WidgetOption::Action(t) => Ok(t),
+            WidgetOption::Handler(i) => Err(Error::new_spanned(i, "expected `action = Type`")),
+        };
+    }
+
+    let mut types = input.generics.type_params();
+
+    match (types.next(), types.next()) {
+        (Some(t), None) => {
+            let ident = &t.ident;
+            Ok(parse_quote!(#ident))
+        }
+
+        _ => Err(Error::new_spanned(
+            &input.ident,
+            "the type of action must be given by `#[widget(action = Type)]`",
+        )),
+    }
+}
+
+/// The accessors of every field and of handlers in particular.
+fn members(fields: &Fields) -> Result<(Vec<TokenStream2>, Vec<TokenStream2>)> {
+    let mut members = Vec::new();
+    let mut handlers = Vec::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => Index::from(i).into_token_stream(),
+        };
+
+        for option in options(&field.attrs)? {
+            match option {
+                WidgetOption::Handler(_) => handlers.push(member.clone()),
+                WidgetOption::Action(t) => return Err(Error::new_spanned(t, "expected `handler`")),
+            }
+        }
+
+        members.push(member);
+    }
+
+    Ok((members, handlers))
+}
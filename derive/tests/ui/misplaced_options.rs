@@ -0,0 +1,24 @@
+use oxidizer::Widget;
+
+#[derive(Debug, Default, Clone, Widget)]
+#[widget(action = u32)]
+struct Stepper {
+    #[widget(action = u32)]
+    value: u32,
+}
+
+#[derive(Debug, Default, Clone, Widget)]
+#[widget(action = u32)]
+struct Counter {
+    #[widget(handle)]
+    value: u32,
+}
+
+#[derive(Debug, Clone, Widget)]
+#[widget(action = u32)]
+enum Toggle {
+    On,
+    Off,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use oxidizer::Widget;
+
+#[derive(Debug, Default, Clone, Widget)]
+struct Stepper {
+    value: u32,
+}
+
+#[derive(Debug, Default, Clone, Widget)]
+#[widget(handler)]
+struct Counter {
+    value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use oxidizer::Widget;
+
+#[derive(Debug, Default, Clone, Widget)]
+#[widget(action = u32, action = String)]
+struct Stepper {
+    value: u32,
+}
+
+#[derive(Debug, Default, Clone, Widget)]
+#[widget(action = u32)]
+#[widget(action = String)]
+struct Counter {
+    value: u32,
+}
+
+fn main() {}
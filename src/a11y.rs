@@ -0,0 +1,358 @@
+//! Accessibility trees derived from widget trees.
+//!
+//! A [`Node`] describes a widget the way assistive technologies expect it, that is in terms of
+//! its [`Role`], name, value and state, while the structure of the widget tree is preserved.
+//! Nodes are identified by the path of the widget they describe, so that events reported by
+//! assistive technologies can be dispatched back through [`Widget::dispatch`].
+//!
+//! [`Widget::Shared`] is transparent and [`Custom`](crate::widget::Custom) widgets are described
+//! as groups of their children.
+
+use crate::{diff::Change, widget::Widget};
+
+/// The kind of user interface element a [`Node`] describes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Role {
+    /// A container of other elements.
+    Group,
+    /// A control that triggers an action when pressed.
+    Button,
+    /// A control that accepts text.
+    TextBox,
+    /// A control that can be checked or unchecked.
+    CheckBox,
+    /// Text that isn't interactive.
+    StaticText,
+    /// Cells aligned in rows and columns under headers.
+    Table,
+    /// A control that lists nested items which may be expanded and selected.
+    Tree,
+    /// A control that switches between pages, named after the one shown.
+    TabList,
+    /// An open dialog drawn above everything else, which traps focus.
+    /// Closed dialogs are described as empty groups.
+    Dialog,
+}
+
+/// The accessible description of a widget along with its children.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Node {
+    /// The path of the widget within the tree.
+    pub id: Vec<usize>,
+    pub role: Role,
+    /// The label of the widget, if it has one.
+    pub name: Option<String>,
+    /// The text held by the widget, if it accepts text.
+    pub value: Option<String>,
+    /// Whether the widget is checked, if it can be checked.
+    pub checked: Option<bool>,
+    /// Whether the widget or any of its ancestors refuses events.
+    pub disabled: bool,
+    /// Whether the text held by the widget can't be changed.
+    pub readonly: bool,
+    /// Whether the widget can receive keyboard focus, which disabled widgets can't.
+    pub focusable: bool,
+    /// The last segment of the path of the first child, which is only nonzero for widgets that
+    /// show a window of their children, see [`Widget::indices`].
+    pub offset: usize,
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    /// Describes `widget` and its descendants, identifying it by `id`.
+    ///
+    /// The widget is assumed not to have disabled ancestors.
+    pub fn new<A>(id: Vec<usize>, widget: &Widget<A>) -> Self {
+        Node::describe(id, widget, false)
+    }
+
+    fn describe<A>(id: Vec<usize>, widget: &Widget<A>, inherited: bool) -> Self {
+        let disabled = inherited || widget.is_disabled();
+
+        let readonly = match widget.resolve() {
+            Widget::Entry(w) => w.readonly,
+            _ => false,
+        };
+
+        let (role, name, value, checked) = match widget.resolve() {
+            Widget::Button(w) => (Role::Button, Some(w.label.clone()), None, None),
+            Widget::Entry(w) => (Role::TextBox, None, Some(w.value.clone()), None),
+            Widget::Checkbox(w) => (Role::CheckBox, Some(w.label.clone()), None, Some(w.value)),
+            Widget::Text(w) => (Role::StaticText, Some(w.content.clone()), None, None),
+            Widget::Table(_) => (Role::Table, None, None, None),
+            Widget::TreeView(_) => (Role::Tree, None, None, None),
+            Widget::Tabs(w) => (Role::TabList, w.labels().get(w.active).cloned(), None, None),
+            Widget::Modal(w) if w.open => (Role::Dialog, Some(w.title.clone()), None, None),
+            _ => (Role::Group, None, None, None),
+        };
+
+        let children = widget
+            .indices()
+            .zip(widget.children())
+            .map(|(i, child)| Node::describe([&id[..], &[i]].concat(), child, disabled))
+            .collect();
+
+        Node {
+            focusable: matches!(
+                role,
+                Role::Button | Role::TextBox | Role::CheckBox | Role::Tree | Role::TabList
+            ) && !disabled,
+            disabled,
+            readonly,
+            id,
+            role,
+            name,
+            value,
+            checked,
+            offset: widget.indices().start,
+            children,
+        }
+    }
+
+    /// Returns the node identified by `id` relative to this node, if any.
+    pub fn get(&self, id: &[usize]) -> Option<&Node> {
+        id.iter().try_fold(self, |n, &i| n.child(i))
+    }
+
+    /// The child identified by the last segment `i` of its path.
+    fn child(&self, i: usize) -> Option<&Node> {
+        self.children.get(i.checked_sub(self.offset)?)
+    }
+
+    /// Iterates over this node and its descendants in depth-first order.
+    pub fn iter(&self) -> impl Iterator<Item = &Node> {
+        let mut stack = vec![self];
+
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    /// The topmost open dialog, that is the last one in depth-first order, if any.
+    pub fn dialog(&self) -> Option<&Node> {
+        self.iter().filter(|n| n.role == Role::Dialog).last()
+    }
+
+    /// Iterates over the nodes that can receive focus in order, which are limited to the
+    /// descendants of the topmost open dialog, if any.
+    pub fn focus_order(&self) -> impl Iterator<Item = &Node> {
+        self.dialog().unwrap_or(self).iter().filter(|n| n.focusable)
+    }
+
+    /// Iterates over the controls, that is focusable nodes, whose name is missing or empty.
+    ///
+    /// Text boxes are exempt, since they are named by the controls around them, as are trees,
+    /// whose items are named by their labels.
+    pub fn unlabeled(&self) -> impl Iterator<Item = &Node> {
+        self.iter()
+            .filter(|n| n.focusable && !matches!(n.role, Role::TextBox | Role::Tree))
+            .filter(|n| n.name.as_deref().map_or(true, str::is_empty))
+    }
+
+    /// Applies the changes that turn a widget tree into another, as computed by
+    /// [`diff`](crate::diff()), so that this node describes the new tree.
+    ///
+    /// # Panics
+    ///
+    /// If the changes don't apply to the tree this node describes.
+    pub fn update<A>(&mut self, changes: &[Change<A>]) {
+        for change in changes {
+            match change {
+                Change::Replaced(id, w) => {
+                    let inherited = match id.split_last() {
+                        Some((_, parent)) => self.get_mut(parent).disabled,
+                        None => false,
+                    };
+
+                    *self.get_mut(id) = Node::describe(id.clone(), w, inherited);
+                }
+
+                Change::Inserted(id, w) => {
+                    let (&i, parent) = id.split_last().expect("the root can't be inserted");
+                    let parent = self.get_mut(parent);
+                    let node = Node::describe(id.clone(), w, parent.disabled);
+                    let at = i - parent.offset;
+                    parent.children.insert(at, node);
+                    parent.renumber_from(at + 1);
+                }
+
+                Change::Removed(id) => {
+                    let (&i, parent) = id.split_last().expect("the root can't be removed");
+                    let parent = self.get_mut(parent);
+                    let at = i - parent.offset;
+                    parent.children.remove(at);
+                    parent.renumber_from(at);
+                }
+            }
+        }
+    }
+
+    fn get_mut(&mut self, id: &[usize]) -> &mut Node {
+        id.iter().fold(self, |n, &i| {
+            let at = i - n.offset;
+            &mut n.children[at]
+        })
+    }
+
+    fn renumber_from(&mut self, start: usize) {
+        let (id, children) = (&self.id, &mut self.children[start..]);
+
+        for (i, child) in children.iter_mut().enumerate() {
+            child.renumber([&id[..], &[self.offset + start + i]].concat());
+        }
+    }
+
+    fn renumber(&mut self, id: Vec<usize>) {
+        self.id = id;
+        self.renumber_from(0);
+    }
+}
+
+impl<'a, 'w, A> From<&'a Widget<'w, A>> for Node {
+    fn from(widget: &'a Widget<'w, A>) -> Self {
+        Node::new(vec![], widget)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diff, widget};
+    use proptest::prelude::*;
+    use std::sync::Arc;
+
+    #[derive(Debug, Default)]
+    struct Action;
+
+    proptest! {
+        #[test]
+        fn roles_and_states(label: String, value: String, checked: bool) {
+            let ui: Widget<Action> = widget!(Column [
+                Button { label: label.clone() }
+                Row [
+                    Entry { value: value.clone() }
+                    Checkbox { label: label.clone(), value: checked }
+                ]
+            ]);
+
+            let root = Node::from(&ui);
+
+            assert_eq!(root.role, Role::Group);
+            assert!(!root.focusable);
+
+            assert_eq!(root.get(&[0]), Some(&Node {
+                id: vec![0],
+                role: Role::Button,
+                name: Some(label.clone()),
+                value: None,
+                checked: None,
+                disabled: false,
+                readonly: false,
+                focusable: true,
+                offset: 0,
+                children: vec![],
+            }));
+
+            assert_eq!(root.get(&[1, 0]).map(|n| (n.role, n.value.clone())), Some((Role::TextBox, Some(value))));
+            assert_eq!(root.get(&[1, 1]).map(|n| (n.role, n.checked)), Some((Role::CheckBox, Some(checked))));
+            assert_eq!(root.get(&[1, 1]).map(|n| &n.id), Some(&vec![1, 1]));
+            assert_eq!(root.get(&[2]), None);
+        }
+
+        #[test]
+        fn ids_are_paths(w: Widget<Action>) {
+            let root = Node::from(&w);
+
+            for node in root.iter() {
+                assert_eq!(root.get(&node.id), Some(node));
+                let widget = w.get(node.id.iter().copied());
+                let inherited = node.id.split_last().and_then(|(_, p)| root.get(p)).map_or(false, |p| p.disabled);
+                assert_eq!(widget.map(|w| Node::describe(node.id.clone(), w, inherited)).as_ref(), Some(node));
+            }
+        }
+
+        #[test]
+        fn shared_is_transparent(w: Widget<Action>) {
+            let shared = Widget::Shared(Arc::new(w.clone()));
+            assert_eq!(Node::from(&shared), Node::from(&w));
+        }
+
+        #[test]
+        fn update_from_diff(x: Widget<Action>, y: Widget<Action>) {
+            let mut node = Node::from(&x);
+            node.update(&diff(&x, &y));
+            assert_eq!(node, Node::from(&y));
+        }
+
+        #[test]
+        fn update_inserts_into_windows_that_were_empty(label: String) {
+            let x: Widget<Action> = widget!(Stack { active: 1usize } [ Text ]);
+            let y: Widget<Action> = widget!(Stack { active: 1usize } [ Text Button { label } ]);
+
+            let mut node = Node::from(&x);
+            node.update(&diff(&x, &y));
+            assert_eq!(node, Node::from(&y));
+        }
+
+        #[test]
+        fn disabled_is_inherited(label: String) {
+            let ui: Widget<Action> = widget!(Column [
+                Row { disabled: true } [ Button { label: label.clone() } Entry ]
+                Entry { readonly: true }
+                Checkbox { label, disabled: true }
+            ]);
+
+            let root = Node::from(&ui);
+            let flags = |id: &[usize]| root.get(id).map(|n| (n.disabled, n.readonly, n.focusable));
+
+            assert_eq!(flags(&[]), Some((false, false, false)));
+            assert_eq!(flags(&[0]), Some((true, false, false)));
+            assert_eq!(flags(&[0, 0]), Some((true, false, false)));
+            assert_eq!(flags(&[0, 1]), Some((true, false, false)));
+            assert_eq!(flags(&[1]), Some((false, true, true)));
+            assert_eq!(flags(&[2]), Some((true, false, false)));
+        }
+
+        #[test]
+        fn focus_is_trapped_in_the_topmost_dialog(title: String, open: bool) {
+            let ui: Widget<Action> = widget!(Column [
+                Button
+                Modal { title: title.clone(), open: true } [ Entry Modal { open } [ Checkbox ] ]
+                Modal [ Button ]
+            ]);
+
+            let root = Node::from(&ui);
+            let ids: Vec<_> = root.focus_order().map(|n| n.id.clone()).collect();
+
+            match open {
+                true => assert_eq!(ids, vec![vec![1, 1, 0]]),
+                false => assert_eq!(ids, vec![vec![1, 0]]),
+            }
+
+            assert_eq!(root.dialog().map(|d| d.id.len()), Some(if open { 2 } else { 1 }));
+            assert_eq!(root.get(&[1]).map(|n| (n.role, n.name.clone())), Some((Role::Dialog, Some(title))));
+            assert_eq!(root.get(&[2]).map(|n| (n.role, n.children.len())), Some((Role::Group, 0)));
+
+            let closed: Widget<Action> = widget!(Row [ Button Modal [ Entry ] ]);
+            let ids: Vec<_> = Node::from(&closed).focus_order().map(|n| n.id.clone()).collect();
+            assert_eq!(ids, vec![vec![0]]);
+        }
+
+        #[test]
+        fn unlabeled_controls(label in ".+") {
+            let ui: Widget<Action> = widget!(Row [
+                Button
+                Button { label: label.clone() }
+                Entry
+                Checkbox
+                Checkbox { label }
+                TreeView
+            ]);
+
+            let ids: Vec<_> = Node::from(&ui).unlabeled().map(|n| n.id.clone()).collect();
+            assert_eq!(ids, vec![vec![0], vec![3]]);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+/// A type-erased future that can be sent across threads.
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// A batch of asynchronous tasks that resolve to actions.
+///
+/// Commands are returned by [`Application::update`](crate::Application::update) and driven by the
+/// [`Runtime`](crate::Runtime), which feeds the resulting actions back into the application.
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::Command;
+///
+/// enum Action {
+///     Fetch,
+///     Fetched(String),
+/// }
+///
+/// let command = Command::perform(async {
+///     // ...
+///     Action::Fetched("response".into())
+/// });
+///
+/// assert_eq!(command.len(), 1);
+/// ```
+pub struct Command<A>(Vec<BoxFuture<A>>);
+
+impl<A> Command<A> {
+    /// A command that does nothing.
+    pub fn none() -> Self {
+        Command(Vec::new())
+    }
+
+    /// A command that resolves `future` into an action.
+    pub fn perform(future: impl Future<Output = A> + Send + 'static) -> Self {
+        Command(vec![Box::pin(future)])
+    }
+
+    /// A command that performs all `commands` concurrently.
+    pub fn batch(commands: impl IntoIterator<Item = Self>) -> Self {
+        Command(commands.into_iter().flat_map(|c| c.0).collect())
+    }
+
+    /// The number of tasks in this command.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether this command does nothing.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<A> Default for Command<A> {
+    fn default() -> Self {
+        Command::none()
+    }
+}
+
+impl<A> IntoIterator for Command<A> {
+    type Item = BoxFuture<A>;
+    type IntoIter = std::vec::IntoIter<BoxFuture<A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<A> fmt::Debug for Command<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Command({})", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::block_on;
+    use proptest::prelude::*;
+
+    #[test]
+    fn none() {
+        assert!(Command::<()>::none().is_empty());
+        assert!(Command::<()>::default().is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn perform(n: u32) {
+            let command = Command::perform(async move { n });
+            assert_eq!(command.len(), 1);
+
+            let actions: Vec<_> = command.into_iter().map(block_on).collect();
+            assert_eq!(actions, vec![n]);
+        }
+
+        #[test]
+        fn batch(ns: Vec<u32>) {
+            let command = Command::batch(ns.iter().map(|&n| Command::perform(async move { n })));
+            assert_eq!(command.len(), ns.len());
+
+            let actions: Vec<_> = command.into_iter().map(block_on).collect();
+            assert_eq!(actions, ns);
+        }
+
+        #[test]
+        fn debug(n in 0usize..10) {
+            let command = Command::batch((0..n).map(|_| Command::perform(async {})));
+            assert_eq!(format!("{:?}", command), format!("Command({})", n));
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use crate::widget::Widget;
+use std::any::{Any, TypeId};
+use std::convert::TryFrom;
+use std::fmt::{self, Debug};
+use std::hash::{Hash, Hasher};
+
+/// A widget that keeps local state across rebuilds.
+///
+/// Components are mounted into widget trees through [`Stateful`](crate::widget::Stateful),
+/// their state is owned by the [`Runtime`](crate::Runtime) and lives for as long as the
+/// component keeps its position in the tree.
+///
+/// Local messages travel through the action type `A` of the widget tree.
+/// Actions produced within the subtree of a component that convert into [`Component::Message`]
+/// are routed to [`Component::update`], every other action bubbles up to the enclosing component,
+/// or ultimately the [`Application`](crate::Application).
+pub trait Component<A>: 'static + Send + Sync + Debug + Clone + Eq + Hash {
+    /// The local state, initialized to its default value when the component is first mounted.
+    type State: 'static + Default;
+
+    /// The local messages, which are carried by actions of type `A`.
+    type Message: TryFrom<A, Error = A>;
+
+    /// Updates the local state, optionally producing an action that bubbles up.
+    fn update(&self, state: &mut Self::State, message: Self::Message) -> Option<A>;
+
+    /// Builds the subtree out of the local state.
+    fn view(&self, state: &Self::State) -> Widget<'static, A>;
+}
+
+trait ErasedComponent<A>: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn dyn_eq(&self, other: &dyn ErasedComponent<A>) -> bool;
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+    fn dyn_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    fn dyn_clone(&self) -> Box<dyn ErasedComponent<A>>;
+    fn init(&self) -> Box<dyn Any>;
+    fn update(&self, state: &mut dyn Any, action: A) -> Result<Option<A>, A>;
+    fn view(&self, state: &dyn Any) -> Widget<'static, A>;
+}
+
+impl<A, C: Component<A>> ErasedComponent<A> for C {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn ErasedComponent<A>) -> bool {
+        other.as_any().downcast_ref::<C>() == Some(self)
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        TypeId::of::<C>().hash(&mut state);
+        Hash::hash(self, &mut state);
+    }
+
+    fn dyn_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ErasedComponent<A>> {
+        Box::new(self.clone())
+    }
+
+    fn init(&self) -> Box<dyn Any> {
+        Box::new(C::State::default())
+    }
+
+    fn update(&self, state: &mut dyn Any, action: A) -> Result<Option<A>, A> {
+        let message = C::Message::try_from(action)?;
+        let state = state.downcast_mut().expect("state type mismatch");
+        Ok(Component::update(self, state, message))
+    }
+
+    fn view(&self, state: &dyn Any) -> Widget<'static, A> {
+        Component::view(self, state.downcast_ref().expect("state type mismatch"))
+    }
+}
+
+/// A type-erased [`Component`].
+///
+/// [`AnyComponent`] is either empty, which is the default, or holds some [`Component`].
+/// It implements [`From<C: Component>`](#impl-From<C>), so it is ergonomic to use with the
+/// [`widget!`](macro@crate::widget) macro.
+///
+/// Note that [`AnyComponent`] deliberately doesn't implement [`Clone`], which is what allows
+/// the blanket [`From`] implementation to coexist with the reflexive `From<T> for T`,
+/// see [`AnyComponent::duplicate`] instead.
+pub struct AnyComponent<A>(Option<Box<dyn ErasedComponent<A>>>);
+
+impl<A> AnyComponent<A> {
+    /// Whether no component is held.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// The [`TypeId`] of the component held, if any.
+    pub fn type_id(&self) -> Option<TypeId> {
+        self.0.as_ref().map(|c| c.as_any().type_id())
+    }
+
+    /// Clones the component held, if any.
+    pub fn duplicate(&self) -> Self {
+        AnyComponent(self.0.as_ref().map(|c| c.dyn_clone()))
+    }
+
+    /// Attempts to downcast to a concrete component type.
+    pub fn downcast_ref<C: Component<A>>(&self) -> Option<&C> {
+        self.0.as_ref().and_then(|c| c.as_any().downcast_ref())
+    }
+
+    pub(crate) fn init(&self) -> Option<Box<dyn Any>> {
+        self.0.as_ref().map(|c| c.init())
+    }
+
+    pub(crate) fn update(&self, state: &mut dyn Any, action: A) -> Result<Option<A>, A> {
+        match &self.0 {
+            Some(c) => c.update(state, action),
+            None => Err(action),
+        }
+    }
+
+    pub(crate) fn view(&self, state: &dyn Any) -> Option<Widget<'static, A>> {
+        self.0.as_ref().map(|c| c.view(state))
+    }
+}
+
+impl<A, C: Component<A>> From<C> for AnyComponent<A> {
+    fn from(component: C) -> Self {
+        AnyComponent(Some(Box::new(component)))
+    }
+}
+
+impl<A> Default for AnyComponent<A> {
+    fn default() -> Self {
+        AnyComponent(None)
+    }
+}
+
+impl<A> Debug for AnyComponent<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(c) => c.dyn_fmt(f),
+            None => f.write_str("None"),
+        }
+    }
+}
+
+impl<A> Eq for AnyComponent<A> {}
+
+impl<A> PartialEq for AnyComponent<A> {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => a.dyn_eq(&**b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<A> Hash for AnyComponent<A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match &self.0 {
+            Some(c) => c.dyn_hash(state),
+            None => TypeId::of::<()>().hash(state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{widget, widget::*};
+    use proptest::prelude::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    enum Action {
+        Counter(Increment),
+        Submit(u32),
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    struct Increment;
+
+    impl From<Increment> for Action {
+        fn from(message: Increment) -> Self {
+            Action::Counter(message)
+        }
+    }
+
+    impl TryFrom<Action> for Increment {
+        type Error = Action;
+
+        fn try_from(action: Action) -> Result<Self, Self::Error> {
+            match action {
+                Action::Counter(m) => Ok(m),
+                a => Err(a),
+            }
+        }
+    }
+
+    /// Counts clicks and submits the count once it reaches a limit.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    struct Counter {
+        limit: u32,
+    }
+
+    impl Component<Action> for Counter {
+        type State = u32;
+        type Message = Increment;
+
+        fn update(&self, count: &mut u32, _: Increment) -> Option<Action> {
+            *count += 1;
+
+            if *count >= self.limit {
+                Some(Action::Submit(std::mem::take(count)))
+            } else {
+                None
+            }
+        }
+
+        fn view(&self, count: &u32) -> Widget<'static, Action> {
+            let handler: fn(&_, &_) -> _ = |_, _| Increment.into();
+            widget!(Button {
+                label: count.to_string(),
+                handler
+            })
+        }
+    }
+
+    fn hash(c: &AnyComponent<Action>) -> u64 {
+        let mut h = DefaultHasher::new();
+        c.hash(&mut h);
+        h.finish()
+    }
+
+    #[test]
+    fn default() {
+        assert!(AnyComponent::<Action>::default().is_empty());
+        assert_eq!(AnyComponent::<Action>::default().type_id(), None);
+        assert_eq!(AnyComponent::<Action>::default().init().map(|_| ()), None);
+    }
+
+    proptest! {
+        #[test]
+        fn from(limit: u32) {
+            let c = AnyComponent::from(Counter { limit });
+            assert!(!c.is_empty());
+            assert_eq!(c.type_id(), Some(TypeId::of::<Counter>()));
+            assert_eq!(c.downcast_ref(), Some(&Counter { limit }));
+        }
+
+        #[test]
+        fn duplicate(limit: u32) {
+            let c = AnyComponent::from(Counter { limit });
+            assert_eq!(c.duplicate(), c);
+            assert_eq!(AnyComponent::<Action>::default().duplicate(), AnyComponent::default());
+        }
+
+        #[test]
+        fn eq_and_hash(x: u32, y: u32) {
+            let a = AnyComponent::from(Counter { limit: x });
+            let b = AnyComponent::from(Counter { limit: y });
+
+            assert_eq!(a == b, x == y);
+            assert_eq!(a == b, hash(&a) == hash(&b));
+            assert_ne!(a, AnyComponent::default());
+        }
+
+        #[test]
+        fn debug(limit: u32) {
+            let c = AnyComponent::from(Counter { limit });
+            assert_eq!(format!("{:?}", c), format!("{:?}", Counter { limit }));
+        }
+
+        #[test]
+        fn update_routes_local_messages(limit in 2u32..) {
+            let c = AnyComponent::from(Counter { limit });
+            let mut state = c.init().unwrap();
+
+            assert_eq!(c.update(&mut *state, Action::Counter(Increment)), Ok(None));
+            assert_eq!(state.downcast_ref(), Some(&1u32));
+            assert_eq!(c.update(&mut *state, Action::Submit(limit)), Err(Action::Submit(limit)));
+            assert_eq!(state.downcast_ref(), Some(&1u32));
+        }
+
+        #[test]
+        fn view_reflects_the_state(limit: u32, count: u32) {
+            let c = AnyComponent::from(Counter { limit });
+            match c.view(&count) {
+                Some(Widget::Button(b)) => assert_eq!(b.label, count.to_string()),
+                w => panic!("expected a button, got {:?}", w),
+            }
+        }
+    }
+}
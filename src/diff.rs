@@ -0,0 +1,256 @@
+use crate::widget::Widget;
+
+/// A change that takes part in turning a widget tree into another.
+#[derive(derivative::Derivative)]
+#[derivative(
+    Debug(bound = ""),
+    Clone(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = ""),
+    Hash(bound = "")
+)]
+pub enum Change<'w, A> {
+    /// The widget at the given path was replaced.
+    Replaced(Vec<usize>, Widget<'w, A>),
+    /// A widget was inserted at the given path.
+    Inserted(Vec<usize>, Widget<'w, A>),
+    /// The widget at the given path was removed.
+    Removed(Vec<usize>),
+}
+
+/// Computes the changes that turn the tree `old` into the tree `new`.
+///
+/// The changes are listed in the order they must be applied,
+/// that is the paths of later changes assume earlier changes have already taken place.
+///
+/// Subtrees that are [`Widget::Shared`] by both trees are skipped without being inspected.
+pub fn diff<'a, 'w: 'a, A>(old: &Widget<'w, A>, new: &'a Widget<'w, A>) -> Vec<Change<'a, A>> {
+    let mut changes = Vec::new();
+    visit(&mut Vec::new(), old, new, &mut changes);
+    changes
+}
+
+fn visit<'a, 'w: 'a, A>(
+    path: &mut Vec<usize>,
+    old: &Widget<'w, A>,
+    new: &'a Widget<'w, A>,
+    changes: &mut Vec<Change<'a, A>>,
+) {
+    if old.ptr_eq(new) {
+        return;
+    }
+
+    use Widget::*;
+    match (old.resolve(), new.resolve()) {
+        (o, n) if o.ptr_eq(n) => {}
+
+        (o @ Row(a), n @ Row(b)) if (&a.style, a.disabled) == (&b.style, b.disabled) => {
+            visit_children(path, o, n, changes)
+        }
+
+        (o @ Column(a), n @ Column(b)) if (&a.style, a.disabled) == (&b.style, b.disabled) => {
+            visit_children(path, o, n, changes)
+        }
+
+        (o @ Scroll(a), n @ Scroll(b)) if a.props() == b.props() => {
+            visit_children(path, o, n, changes)
+        }
+
+        (o @ VirtualList(a), n @ VirtualList(b)) if a.props() == b.props() => {
+            visit_children(path, o, n, changes)
+        }
+
+        (o @ Table(a), n @ Table(b)) if a.props() == b.props() => {
+            visit_children(path, o, n, changes)
+        }
+
+        (o @ Grid(a), n @ Grid(b)) if a.props() == b.props() => visit_children(path, o, n, changes),
+
+        (o @ Stack(a), n @ Stack(b)) if a.props() == b.props() => {
+            visit_children(path, o, n, changes)
+        }
+
+        (o @ Tabs(a), n @ Tabs(b)) if a.props() == b.props() => visit_children(path, o, n, changes),
+
+        (o @ Modal(a), n @ Modal(b)) if a.props() == b.props() => {
+            visit_children(path, o, n, changes)
+        }
+
+        (o @ Stateful(a), n @ Stateful(b)) if a.key == b.key && a.component == b.component => {
+            visit_children(path, o, n, changes)
+        }
+
+        (o, n) if o != n => changes.push(Change::Replaced(path.clone(), new.into())),
+
+        _ => {}
+    }
+}
+
+fn visit_children<'a, 'w: 'a, A>(
+    path: &mut Vec<usize>,
+    old: &Widget<'w, A>,
+    new: &'a Widget<'w, A>,
+    changes: &mut Vec<Change<'a, A>>,
+) {
+    let (os, ns) = (old.children(), new.children());
+    let start = new.indices().start;
+
+    for (i, (o, n)) in os.iter().zip(ns).enumerate() {
+        path.push(start + i);
+        visit(path, o, n, changes);
+        path.pop();
+    }
+
+    for (i, n) in ns.iter().enumerate().skip(os.len()) {
+        changes.push(Change::Inserted(
+            [&path[..], &[start + i]].concat(),
+            n.into(),
+        ));
+    }
+
+    for i in (ns.len()..os.len()).rev() {
+        changes.push(Change::Removed([&path[..], &[start + i]].concat()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::*;
+    use proptest::prelude::*;
+    use std::sync::Arc;
+
+    #[derive(Default)]
+    struct Action;
+
+    fn children_mut<'a, 'w>(
+        widget: &'a mut Widget<'w, Action>,
+    ) -> &'a mut Box<[Widget<'w, Action>]> {
+        while let Widget::Shared(w) = widget {
+            *widget = (**w).clone();
+        }
+
+        match widget {
+            Widget::Row(r) => &mut r.make_owned().children,
+            Widget::Column(c) => &mut c.make_owned().children,
+            Widget::Scroll(s) => &mut s.make_owned().children,
+            Widget::VirtualList(l) => &mut l.make_owned().rows,
+            Widget::Table(t) => &mut t.make_owned().cells,
+            Widget::Grid(g) => &mut g.make_owned().children,
+            Widget::Stack(s) => &mut s.make_owned().children,
+            Widget::Tabs(t) => &mut t.make_owned().pages,
+            Widget::Modal(m) => &mut m.make_owned().children,
+            Widget::Stateful(s) => &mut s.make_owned().children,
+            w => panic!("expected a container, got {:?}", w),
+        }
+    }
+
+    fn node_mut<'a, 'w>(
+        mut widget: &'a mut Widget<'w, Action>,
+        path: &[usize],
+    ) -> &'a mut Widget<'w, Action> {
+        for &i in path {
+            let start = match widget.resolve() {
+                Widget::VirtualList(l) => l.visible().start,
+                _ => 0,
+            };
+
+            widget = &mut children_mut(widget)[i - start];
+        }
+
+        widget
+    }
+
+    fn apply<'w>(widget: &mut Widget<'w, Action>, change: Change<'w, Action>) {
+        match change {
+            Change::Replaced(p, w) => *node_mut(widget, &p) = w,
+            Change::Inserted(p, w) => {
+                let (i, p) = p.split_last().unwrap();
+                let children = children_mut(node_mut(widget, p));
+                let mut v = std::mem::take(children).into_vec();
+                v.insert(*i, w);
+                *children = v.into();
+            }
+            Change::Removed(p) => {
+                let (i, p) = p.split_last().unwrap();
+                let children = children_mut(node_mut(widget, p));
+                let mut v = std::mem::take(children).into_vec();
+                v.remove(*i);
+                *children = v.into();
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn identical_trees_have_no_changes(w: Widget<Action>) {
+            assert_eq!(diff(&w, &w), vec![]);
+            assert_eq!(diff(&w, &w.clone()), vec![]);
+        }
+
+        #[test]
+        fn shared_subtrees_are_skipped(w: Widget<Action>) {
+            let shared = Widget::from(Arc::new(w));
+            let x = Widget::from(Row { children: Box::new([shared.clone()]), ..Default::default() });
+            let y = Widget::from(Column { children: Box::new([shared]), ..Default::default() });
+
+            assert_eq!(diff(&x.children()[0], &y.children()[0]), vec![]);
+        }
+
+        #[test]
+        fn changes_turn_old_into_new(x: Widget<Action>, y: Widget<Action>) {
+            let mut w = x.clone();
+
+            for change in diff(&x, &y) {
+                apply(&mut w, change);
+            }
+
+            assert_eq!(w, y);
+        }
+
+        #[test]
+        fn replaced_widgets_are_reported_at_their_path(x: Button<Action>, y: Checkbox<Action>) {
+            let (x, y) = (Widget::from(x), Widget::from(y));
+            assert_eq!(diff(&x, &y), vec![Change::Replaced(vec![], (&y).into())]);
+
+            let (x, y) = (Widget::from(Row { children: Box::new([x]), ..Default::default() }), Widget::from(Row { children: Box::new([y.clone()]), ..Default::default() }));
+            assert_eq!(diff(&x, &y), vec![Change::Replaced(vec![0], y[[0usize]].clone())]);
+        }
+
+        #[test]
+        fn restyled_containers_are_replaced(w: Widget<Action>) {
+            let x = Widget::from(Row { children: Box::new([w.clone()]), ..Default::default() });
+            let y = Widget::from(Row { children: Box::new([w.clone()]), style: "primary".into(), ..Default::default() });
+            assert_eq!(diff(&x, &y), vec![Change::Replaced(vec![], (&y).into())]);
+
+            let z = Widget::from(Row { children: Box::new([w]), disabled: true, ..Default::default() });
+            assert_eq!(diff(&x, &z), vec![Change::Replaced(vec![], (&z).into())]);
+        }
+
+        #[test]
+        fn inserted_and_removed_widgets_are_reported_at_their_path(w: Widget<Action>) {
+            let x = Widget::from(Column::<Action>::default());
+            let y = Widget::from(Column { children: Box::new([w.clone(), w]), ..Default::default() });
+
+            assert_eq!(diff(&x, &y), vec![Change::Inserted(vec![0], y[[0usize]].clone()), Change::Inserted(vec![1], y[[1usize]].clone())]);
+            assert_eq!(diff(&y, &x), vec![Change::Removed(vec![1]), Change::Removed(vec![0])]);
+        }
+
+        #[test]
+        fn rows_of_virtual_lists_are_reported_at_their_logical_index(offset in 20..1000u32) {
+            let row: fn(usize) -> Widget<'static, Action> = |i| Button { label: i.to_string(), ..Default::default() }.into();
+            let list = VirtualList::new(100, 20, row).viewport(40).scroll(offset);
+            let x = Widget::from(list.clone());
+
+            let mut restyled = list.clone();
+            restyled.rows[0] = Button { label: "first".into(), ..Default::default() }.into();
+            let y = Widget::from(restyled);
+
+            let start = list.visible().start;
+            assert_eq!(diff(&x, &y), vec![Change::Replaced(vec![start], y[[start]].clone())]);
+
+            let z = Widget::from(list.scroll(offset - 20));
+            assert_eq!(diff(&x, &z), vec![Change::Replaced(vec![], (&z).into())]);
+        }
+    }
+}
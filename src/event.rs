@@ -1,8 +1,14 @@
+mod batch;
 mod changed;
+mod dismissed;
+mod node;
 
+pub use batch::*;
 pub use changed::*;
+pub use dismissed::*;
+pub use node::*;
 
-use crate::Kind;
+use crate::{Kind, Variant};
 use maybe_owned::MaybeOwned;
 
 /// The semantic representation of an event.
@@ -11,10 +17,120 @@ pub enum Event<'e> {
     Entered(MaybeOwned<'e, Entered>),
     Toggled(MaybeOwned<'e, Toggled>),
     Clicked(MaybeOwned<'e, Clicked>),
+    Scrolled(MaybeOwned<'e, Scrolled>),
+    Sorted(MaybeOwned<'e, Sorted>),
+    RowSelected(MaybeOwned<'e, RowSelected>),
+    Expanded(MaybeOwned<'e, Expanded>),
+    Collapsed(MaybeOwned<'e, Collapsed>),
+    Selected(MaybeOwned<'e, Selected>),
+    Dismissed(MaybeOwned<'e, Dismissed>),
 }
 
 impl<'a, 'e> Kind<Event<'a>> for Event<'e> {}
 
+impl<'e> Event<'e> {
+    /// Returns a reference to the concrete event, if it is of kind `E`.
+    pub fn downcast_ref<E: FromEvent>(&self) -> Option<&E> {
+        E::from_event(self)
+    }
+}
+
+/// The concrete kinds of [`Event`].
+pub trait FromEvent: for<'e> Variant<Event<'e>> {
+    /// Returns a reference to the concrete event, if `event` is of this kind.
+    fn from_event<'a>(event: &'a Event) -> Option<&'a Self>;
+}
+
+impl FromEvent for Entered {
+    fn from_event<'a>(event: &'a Event) -> Option<&'a Self> {
+        match event {
+            Event::Entered(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl FromEvent for Toggled {
+    fn from_event<'a>(event: &'a Event) -> Option<&'a Self> {
+        match event {
+            Event::Toggled(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl FromEvent for Clicked {
+    fn from_event<'a>(event: &'a Event) -> Option<&'a Self> {
+        match event {
+            Event::Clicked(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl FromEvent for Scrolled {
+    fn from_event<'a>(event: &'a Event) -> Option<&'a Self> {
+        match event {
+            Event::Scrolled(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl FromEvent for Sorted {
+    fn from_event<'a>(event: &'a Event) -> Option<&'a Self> {
+        match event {
+            Event::Sorted(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl FromEvent for RowSelected {
+    fn from_event<'a>(event: &'a Event) -> Option<&'a Self> {
+        match event {
+            Event::RowSelected(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl FromEvent for Expanded {
+    fn from_event<'a>(event: &'a Event) -> Option<&'a Self> {
+        match event {
+            Event::Expanded(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl FromEvent for Collapsed {
+    fn from_event<'a>(event: &'a Event) -> Option<&'a Self> {
+        match event {
+            Event::Collapsed(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl FromEvent for Selected {
+    fn from_event<'a>(event: &'a Event) -> Option<&'a Self> {
+        match event {
+            Event::Selected(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl FromEvent for Dismissed {
+    fn from_event<'a>(event: &'a Event) -> Option<&'a Self> {
+        match event {
+            Event::Dismissed(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
 impl<'a: 'f, 'e: 'f, 'f> From<&'a Event<'e>> for Event<'f> {
     fn from(event: &'a Event<'e>) -> Self {
         use Event::*;
@@ -22,6 +138,13 @@ impl<'a: 'f, 'e: 'f, 'f> From<&'a Event<'e>> for Event<'f> {
             Entered(e) => (&**e).into(),
             Toggled(e) => (&**e).into(),
             Clicked(e) => (&**e).into(),
+            Scrolled(e) => (&**e).into(),
+            Sorted(e) => (&**e).into(),
+            RowSelected(e) => (&**e).into(),
+            Expanded(e) => (&**e).into(),
+            Collapsed(e) => (&**e).into(),
+            Selected(e) => (&**e).into(),
+            Dismissed(e) => (&**e).into(),
         }
     }
 }
@@ -62,6 +185,90 @@ impl<'a: 'e, 'e> From<&'a Clicked> for Event<'e> {
     }
 }
 
+impl<'e> From<Scrolled> for Event<'e> {
+    fn from(event: Scrolled) -> Self {
+        Event::Scrolled(event.into())
+    }
+}
+
+impl<'a: 'e, 'e> From<&'a Scrolled> for Event<'e> {
+    fn from(event: &'a Scrolled) -> Self {
+        Event::Scrolled(event.into())
+    }
+}
+
+impl<'e> From<Sorted> for Event<'e> {
+    fn from(event: Sorted) -> Self {
+        Event::Sorted(event.into())
+    }
+}
+
+impl<'a: 'e, 'e> From<&'a Sorted> for Event<'e> {
+    fn from(event: &'a Sorted) -> Self {
+        Event::Sorted(event.into())
+    }
+}
+
+impl<'e> From<RowSelected> for Event<'e> {
+    fn from(event: RowSelected) -> Self {
+        Event::RowSelected(event.into())
+    }
+}
+
+impl<'a: 'e, 'e> From<&'a RowSelected> for Event<'e> {
+    fn from(event: &'a RowSelected) -> Self {
+        Event::RowSelected(event.into())
+    }
+}
+
+impl<'e> From<Expanded> for Event<'e> {
+    fn from(event: Expanded) -> Self {
+        Event::Expanded(event.into())
+    }
+}
+
+impl<'a: 'e, 'e> From<&'a Expanded> for Event<'e> {
+    fn from(event: &'a Expanded) -> Self {
+        Event::Expanded(event.into())
+    }
+}
+
+impl<'e> From<Collapsed> for Event<'e> {
+    fn from(event: Collapsed) -> Self {
+        Event::Collapsed(event.into())
+    }
+}
+
+impl<'a: 'e, 'e> From<&'a Collapsed> for Event<'e> {
+    fn from(event: &'a Collapsed) -> Self {
+        Event::Collapsed(event.into())
+    }
+}
+
+impl<'e> From<Selected> for Event<'e> {
+    fn from(event: Selected) -> Self {
+        Event::Selected(event.into())
+    }
+}
+
+impl<'a: 'e, 'e> From<&'a Selected> for Event<'e> {
+    fn from(event: &'a Selected) -> Self {
+        Event::Selected(event.into())
+    }
+}
+
+impl<'e> From<Dismissed> for Event<'e> {
+    fn from(event: Dismissed) -> Self {
+        Event::Dismissed(event.into())
+    }
+}
+
+impl<'a: 'e, 'e> From<&'a Dismissed> for Event<'e> {
+    fn from(event: &'a Dismissed) -> Self {
+        Event::Dismissed(event.into())
+    }
+}
+
 #[cfg(test)]
 use proptest::{arbitrary::Arbitrary, prelude::*};
 
@@ -75,6 +282,13 @@ impl Arbitrary for Event<'static> {
             any::<Entered>().prop_map_into(),
             any::<Toggled>().prop_map_into(),
             any::<Clicked>().prop_map_into(),
+            any::<Scrolled>().prop_map_into(),
+            any::<Sorted>().prop_map_into(),
+            any::<RowSelected>().prop_map_into(),
+            any::<Expanded>().prop_map_into(),
+            any::<Collapsed>().prop_map_into(),
+            any::<Selected>().prop_map_into(),
+            any::<Dismissed>().prop_map_into(),
         ]
         .boxed()
     }
@@ -109,6 +323,86 @@ mod tests {
             assert_eq!(Event::from(&Event::from(e)), Event::Clicked(Borrowed(&e)));
         }
 
+        #[test]
+        fn from_scrolled(e: Scrolled) {
+            assert_eq!(Event::from(&e), Event::Scrolled(Borrowed(&e)));
+            assert_eq!(Event::from(e), Event::Scrolled(Owned(e)));
+            assert_eq!(Event::from(&Event::from(e)), Event::Scrolled(Borrowed(&e)));
+        }
+
+        #[test]
+        fn from_sorted(e: Sorted) {
+            assert_eq!(Event::from(&e), Event::Sorted(Borrowed(&e)));
+            assert_eq!(Event::from(e), Event::Sorted(Owned(e)));
+            assert_eq!(Event::from(&Event::from(e)), Event::Sorted(Borrowed(&e)));
+        }
+
+        #[test]
+        fn from_row_selected(e: RowSelected) {
+            assert_eq!(Event::from(&e), Event::RowSelected(Borrowed(&e)));
+            assert_eq!(Event::from(e), Event::RowSelected(Owned(e)));
+            assert_eq!(Event::from(&Event::from(e)), Event::RowSelected(Borrowed(&e)));
+        }
+
+        #[test]
+        fn from_expanded(e: Expanded) {
+            assert_eq!(Event::from(&e), Event::Expanded(Borrowed(&e)));
+            assert_eq!(Event::from(e.clone()), Event::Expanded(Owned(e.clone())));
+            assert_eq!(Event::from(&Event::from(e.clone())), Event::Expanded(Borrowed(&e)));
+        }
+
+        #[test]
+        fn from_collapsed(e: Collapsed) {
+            assert_eq!(Event::from(&e), Event::Collapsed(Borrowed(&e)));
+            assert_eq!(Event::from(e.clone()), Event::Collapsed(Owned(e.clone())));
+            assert_eq!(Event::from(&Event::from(e.clone())), Event::Collapsed(Borrowed(&e)));
+        }
+
+        #[test]
+        fn from_selected(e: Selected) {
+            assert_eq!(Event::from(&e), Event::Selected(Borrowed(&e)));
+            assert_eq!(Event::from(e.clone()), Event::Selected(Owned(e.clone())));
+            assert_eq!(Event::from(&Event::from(e.clone())), Event::Selected(Borrowed(&e)));
+        }
+
+        #[test]
+        fn from_dismissed(e: Dismissed) {
+            assert_eq!(Event::from(&e), Event::Dismissed(Borrowed(&e)));
+            assert_eq!(Event::from(e), Event::Dismissed(Owned(e)));
+            assert_eq!(Event::from(&Event::from(e)), Event::Dismissed(Borrowed(&e)));
+        }
+
+        #[test]
+        fn downcast_ref(e: Event) {
+            match &e {
+                Event::Entered(x) => assert_eq!(e.downcast_ref::<Entered>(), Some(&**x)),
+                Event::Toggled(x) => assert_eq!(e.downcast_ref::<Toggled>(), Some(&**x)),
+                Event::Clicked(x) => assert_eq!(e.downcast_ref::<Clicked>(), Some(&**x)),
+                Event::Scrolled(x) => assert_eq!(e.downcast_ref::<Scrolled>(), Some(&**x)),
+                Event::Sorted(x) => assert_eq!(e.downcast_ref::<Sorted>(), Some(&**x)),
+                Event::RowSelected(x) => assert_eq!(e.downcast_ref::<RowSelected>(), Some(&**x)),
+                Event::Expanded(x) => assert_eq!(e.downcast_ref::<Expanded>(), Some(&**x)),
+                Event::Collapsed(x) => assert_eq!(e.downcast_ref::<Collapsed>(), Some(&**x)),
+                Event::Selected(x) => assert_eq!(e.downcast_ref::<Selected>(), Some(&**x)),
+                Event::Dismissed(x) => assert_eq!(e.downcast_ref::<Dismissed>(), Some(&**x)),
+            }
+
+            let kinds = [
+                e.downcast_ref::<Entered>().is_some(),
+                e.downcast_ref::<Toggled>().is_some(),
+                e.downcast_ref::<Clicked>().is_some(),
+                e.downcast_ref::<Scrolled>().is_some(),
+                e.downcast_ref::<Sorted>().is_some(),
+                e.downcast_ref::<RowSelected>().is_some(),
+                e.downcast_ref::<Expanded>().is_some(),
+                e.downcast_ref::<Collapsed>().is_some(),
+                e.downcast_ref::<Selected>().is_some(),
+                e.downcast_ref::<Dismissed>().is_some(),
+            ];
+
+            assert_eq!(kinds.iter().filter(|&&k| k).count(), 1);
+        }
+
         #[test]
         fn clone(e: Event) {
             assert_eq!(e.clone(), e);
@@ -0,0 +1,202 @@
+use crate::{event::Event, TreePath};
+use std::mem::discriminant;
+
+/// A queue of events within a frame, which coalesces consecutive changes to the same widget.
+///
+/// Consecutive [`Entered`](crate::event::Entered), [`Toggled`](crate::event::Toggled) or
+/// [`Scrolled`](crate::event::Scrolled) events targeting the same path are merged, such that only
+/// the last value is delivered.
+/// Events of any other kind, such as [`Clicked`](crate::event::Clicked) or
+/// [`Sorted`](crate::event::Sorted), are never coalesced, since every one of them counts.
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::event::{Batch, Entered};
+///
+/// let mut batch = Batch::new();
+/// batch.push(vec![0usize], Entered { value: "h".into() });
+/// batch.push(vec![0usize], Entered { value: "hi".into() });
+///
+/// assert_eq!(batch.len(), 1);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Batch<'e> {
+    events: Vec<(Vec<usize>, Event<'e>)>,
+}
+
+impl<'e> Batch<'e> {
+    /// An empty batch.
+    pub fn new() -> Self {
+        Batch::default()
+    }
+
+    /// Queues `event` targeting `path`, coalescing it with the last event queued if possible.
+    pub fn push<S: Into<usize>>(
+        &mut self,
+        path: impl TreePath<Segment = S>,
+        event: impl Into<Event<'e>>,
+    ) {
+        let path: Vec<_> = path.segments().into_iter().map(Into::into).collect();
+        let event = event.into();
+
+        match self.events.last_mut() {
+            Some((p, e)) if *p == path && coalesces(e, &event) => *e = event,
+            _ => self.events.push((path, event)),
+        }
+    }
+
+    /// The number of events queued.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Whether no events are queued.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Removes all events queued, in order.
+    pub fn drain(&mut self) -> std::vec::Drain<'_, (Vec<usize>, Event<'e>)> {
+        self.events.drain(..)
+    }
+}
+
+/// Whether `next` supersedes `previous`, that is both carry the latest value of the same kind.
+fn coalesces(previous: &Event, next: &Event) -> bool {
+    let value = matches!(
+        next,
+        Event::Entered(_) | Event::Toggled(_) | Event::Scrolled(_)
+    );
+
+    value && discriminant(previous) == discriminant(next)
+}
+
+impl<'e> IntoIterator for Batch<'e> {
+    type Item = (Vec<usize>, Event<'e>);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.into_iter()
+    }
+}
+
+impl<'e, S: Into<usize>, P: TreePath<Segment = S>, E: Into<Event<'e>>> Extend<(P, E)>
+    for Batch<'e>
+{
+    fn extend<I: IntoIterator<Item = (P, E)>>(&mut self, iter: I) {
+        for (path, event) in iter {
+            self.push(path, event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn entered_is_coalesced(path: Vec<usize>, values: Vec<String>) {
+            let mut batch = Batch::new();
+            batch.extend(values.iter().map(|v| (path.clone(), Entered { value: v.clone() })));
+
+            let expected: Vec<_> = values
+                .last()
+                .map(|v| (path.clone(), Entered { value: v.clone() }.into()))
+                .into_iter()
+                .collect();
+
+            assert_eq!(batch.into_iter().collect::<Vec<_>>(), expected);
+        }
+
+        #[test]
+        fn toggled_is_coalesced(path: Vec<usize>, values: Vec<bool>) {
+            let mut batch = Batch::new();
+            batch.extend(values.iter().map(|&value| (path.clone(), Toggled { value })));
+
+            let expected: Vec<_> = values
+                .last()
+                .map(|&value| (path.clone(), Toggled { value }.into()))
+                .into_iter()
+                .collect();
+
+            assert_eq!(batch.into_iter().collect::<Vec<_>>(), expected);
+        }
+
+        #[test]
+        fn clicked_is_never_coalesced(path: Vec<usize>, n in 0usize..10) {
+            let mut batch = Batch::new();
+            batch.extend((0..n).map(|_| (path.clone(), Clicked::default())));
+            assert_eq!(batch.len(), n);
+        }
+
+        #[test]
+        fn sorted_and_row_selected_are_never_coalesced(path: Vec<usize>, events: Vec<(Sorted, RowSelected)>) {
+            let mut batch = Batch::new();
+
+            for (s, r) in &events {
+                batch.push(path.clone(), s);
+                batch.push(path.clone(), s);
+                batch.push(path.clone(), r);
+                batch.push(path.clone(), r);
+            }
+
+            assert_eq!(batch.len(), events.len() * 4);
+        }
+
+        #[test]
+        fn expanded_and_collapsed_are_never_coalesced(path: Vec<usize>, nodes: Vec<(Expanded, Collapsed)>) {
+            let mut batch = Batch::new();
+
+            for (e, c) in &nodes {
+                batch.push(path.clone(), e);
+                batch.push(path.clone(), e);
+                batch.push(path.clone(), c);
+            }
+
+            assert_eq!(batch.len(), nodes.len() * 3);
+        }
+
+        #[test]
+        fn selected_and_dismissed_are_never_coalesced(path: Vec<usize>, n in 0usize..10, s: Selected) {
+            let mut batch = Batch::new();
+            batch.extend((0..n).map(|_| (path.clone(), Event::from(s.clone()))));
+            batch.extend((0..n).map(|_| (path.clone(), Dismissed)));
+            assert_eq!(batch.len(), n * 2);
+        }
+
+        #[test]
+        fn different_paths_are_not_coalesced(x: Vec<usize>, y: Vec<usize>, e: Entered) {
+            prop_assume!(x != y);
+
+            let mut batch = Batch::new();
+            batch.push(x.clone(), e.clone());
+            batch.push(y.clone(), e.clone());
+            batch.push(x.clone(), e.clone());
+
+            assert_eq!(batch.len(), 3);
+        }
+
+        #[test]
+        fn different_kinds_are_not_coalesced(path: Vec<usize>, e: Entered, t: Toggled) {
+            let mut batch = Batch::new();
+            batch.push(path.clone(), e.clone());
+            batch.push(path.clone(), t);
+            batch.push(path.clone(), e);
+
+            assert_eq!(batch.len(), 3);
+        }
+
+        #[test]
+        fn drain(events: Vec<(Vec<usize>, Event)>) {
+            let mut batch = Batch::new();
+            batch.extend(events);
+
+            let expected = batch.clone().into_iter().collect::<Vec<_>>();
+            assert_eq!(batch.drain().collect::<Vec<_>>(), expected);
+            assert!(batch.is_empty());
+        }
+    }
+}
@@ -22,9 +22,53 @@ pub type Clicked = Changed<()>;
 
 impl<'e> Variant<Event<'e>> for Clicked {}
 
+/// Convenience alias for an event that changes the scroll offset, horizontally and vertically,
+/// in logical pixels.
+pub type Scrolled = Changed<(u32, u32)>;
+
+impl<'e> Variant<Event<'e>> for Scrolled {}
+
+/// The direction in which a column is sorted.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Order {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl Order {
+    /// The opposite direction.
+    pub fn reverse(self) -> Self {
+        match self {
+            Order::Ascending => Order::Descending,
+            Order::Descending => Order::Ascending,
+        }
+    }
+}
+
+/// Convenience alias for an event that sorts a table by the column at some index.
+pub type Sorted = Changed<(usize, Order)>;
+
+impl<'e> Variant<Event<'e>> for Sorted {}
+
+/// Convenience alias for an event that selects the row of a table at some index.
+pub type RowSelected = Changed<usize>;
+
+impl<'e> Variant<Event<'e>> for RowSelected {}
+
 #[cfg(test)]
 use proptest::{arbitrary::Arbitrary, prelude::*};
 
+#[cfg(test)]
+impl Arbitrary for Order {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![Just(Order::Ascending), Just(Order::Descending)].boxed()
+    }
+}
+
 #[cfg(test)]
 impl<T: 'static + Arbitrary> Arbitrary for Changed<T> {
     type Parameters = ();
@@ -46,6 +90,18 @@ mod tests {
         assert_eq!(Entered::default(), Changed { value: "".into() });
         assert_eq!(Toggled::default(), Changed { value: false });
         assert_eq!(Clicked::default(), Clicked { value: () });
+        assert_eq!(
+            Sorted::default(),
+            Changed {
+                value: (0, Order::Ascending)
+            }
+        );
+    }
+
+    #[test]
+    fn reverse() {
+        assert_eq!(Order::Ascending.reverse(), Order::Descending);
+        assert_eq!(Order::Descending.reverse(), Order::Ascending);
     }
 
     proptest! {
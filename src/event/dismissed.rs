@@ -0,0 +1,21 @@
+use crate::{event::Event, Variant};
+
+/// An event that dismisses a [`Modal`](crate::widget::Modal), such as by pressing escape or
+/// clicking outside of it.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Dismissed;
+
+impl<'e> Variant<Event<'e>> for Dismissed {}
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, prelude::*};
+
+#[cfg(test)]
+impl Arbitrary for Dismissed {
+    type Parameters = ();
+    type Strategy = Just<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Dismissed)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{event::Event, Variant};
+
+/// An event that expands the node at `path` within a [`TreeView`](crate::widget::TreeView).
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Expanded {
+    pub path: Vec<usize>,
+}
+
+impl<'e> Variant<Event<'e>> for Expanded {}
+
+/// An event that collapses the node at `path` within a [`TreeView`](crate::widget::TreeView).
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Collapsed {
+    pub path: Vec<usize>,
+}
+
+impl<'e> Variant<Event<'e>> for Collapsed {}
+
+/// An event that selects the node at `path` within a [`TreeView`](crate::widget::TreeView),
+/// or the tab at `[i]` within [`Tabs`](crate::widget::Tabs).
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Selected {
+    pub path: Vec<usize>,
+}
+
+impl<'e> Variant<Event<'e>> for Selected {}
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, collection::vec, prelude::*};
+
+#[cfg(test)]
+fn path() -> impl Strategy<Value = Vec<usize>> {
+    vec(0..8usize, 0..4)
+}
+
+#[cfg(test)]
+impl Arbitrary for Expanded {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        path().prop_map(|path| Expanded { path }).boxed()
+    }
+}
+
+#[cfg(test)]
+impl Arbitrary for Collapsed {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        path().prop_map(|path| Collapsed { path }).boxed()
+    }
+}
+
+#[cfg(test)]
+impl Arbitrary for Selected {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        path().prop_map(|path| Selected { path }).boxed()
+    }
+}
@@ -0,0 +1,92 @@
+use crate::command::BoxFuture;
+use std::future::Future;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+/// Drives futures to completion on behalf of the [`Runtime`](crate::Runtime).
+///
+/// Implement this trait to plug the runtime into an existing async executor.
+pub trait Executor {
+    fn spawn(&self, future: BoxFuture<()>);
+}
+
+/// An [`Executor`] that blocks on every future in a dedicated thread.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ThreadExecutor;
+
+impl Executor for ThreadExecutor {
+    fn spawn(&self, future: BoxFuture<()>) {
+        thread::spawn(move || block_on(future));
+    }
+}
+
+pub(crate) struct Unpark(pub(crate) Thread);
+
+impl Wake for Unpark {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Blocks the current thread on `future`.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::pin::Pin;
+    use std::sync::mpsc::channel;
+
+    /// A future that is pending once, waking itself up from another thread.
+    struct Yield(bool);
+
+    impl Future for Yield {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                let waker = cx.waker().clone();
+                thread::spawn(move || waker.wake());
+                Poll::Pending
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn block_on_resolves_the_future(n: u32) {
+            assert_eq!(block_on(async move {
+                Yield(false).await;
+                n
+            }), n);
+        }
+
+        #[test]
+        fn thread_executor_drives_the_future(n: u32) {
+            let (tx, rx) = channel();
+
+            ThreadExecutor.spawn(Box::pin(async move {
+                Yield(false).await;
+                tx.send(n).unwrap();
+            }));
+
+            assert_eq!(rx.recv(), Ok(n));
+        }
+    }
+}
@@ -0,0 +1,454 @@
+//! Forms made of typed fields and their validation.
+//!
+//! A [`Field`] holds the raw input of a control, such as the text of an [`Entry`] or the state of
+//! a [`Checkbox`], from which a typed [`Value`] is parsed and checked against [`Validator`]s.
+//! Fields live in the state of the application, which updates them from the actions produced by
+//! the handlers of their controls.
+//!
+//! Fields render their controls along with a [`Text`](crate::widget::Text) per error message, which are only shown
+//! once the field has been edited or [touched](Field::touch), and [`submit`] renders a button
+//! that is disabled, and thus refuses clicks, unless every field is valid.
+//!
+//! ## Example
+//! ```rust
+//! use oxidizer::{event::*, form::*, widget, widget::*, Widget};
+//!
+//! #[derive(Debug, Eq, PartialEq)]
+//! enum Action {
+//!     Name(String),
+//!     Age(String),
+//!     Submit,
+//! }
+//!
+//! let mut name = Field::<String>::new("Name").with(Validator::Required);
+//! let mut age = Field::<u8>::new("Age").with(Validator::Range(18..=99));
+//!
+//! let ui = |name: &Field<String>, age: &Field<u8>| -> Widget<'static, Action> {
+//!     widget!(Column [
+//!         @{ name.entry(|_, e| Action::Name(e.value.clone())) }
+//!         @{ age.entry(|_, e| Action::Age(e.value.clone())) }
+//!         @{ submit("Register", &[name, age], |_, _| Action::Submit) }
+//!     ])
+//! };
+//!
+//! assert_eq!(ui(&name, &age).dispatch(vec![2usize], &Clicked::default().into()), None);
+//!
+//! name.set("Alice".to_string());
+//! age.set("42".to_string());
+//!
+//! assert_eq!(ui(&name, &age).dispatch(vec![2usize], &Clicked::default().into()), Some(Action::Submit));
+//! assert_eq!(age.value(), Ok(42));
+//! ```
+
+use crate::event::{Clicked, Entered, Toggled};
+use crate::widget;
+use crate::widget::{Button, Checkbox, Entry, Widget};
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "regex")]
+pub use regex::Regex;
+
+/// A type that can be parsed from the input of a control.
+pub trait Value: Sized + PartialOrd + Display {
+    /// The raw input of the control, which is a [`String`] for [`Entry`] and a [`bool`] for
+    /// [`Checkbox`].
+    type Input: Debug + Default + Clone + Eq + Hash;
+
+    /// Parses `input`, failing with an error message.
+    fn parse(input: &Self::Input) -> Result<Self, String>;
+
+    /// Whether `input` is missing, which [`Validator::Required`] refuses.
+    fn is_blank(input: &Self::Input) -> bool;
+}
+
+impl Value for String {
+    type Input = String;
+
+    fn parse(input: &String) -> Result<Self, String> {
+        Ok(input.clone())
+    }
+
+    fn is_blank(input: &String) -> bool {
+        input.trim().is_empty()
+    }
+}
+
+/// A checkbox is blank unless checked.
+impl Value for bool {
+    type Input = bool;
+
+    fn parse(input: &bool) -> Result<Self, String> {
+        Ok(*input)
+    }
+
+    fn is_blank(input: &bool) -> bool {
+        !input
+    }
+}
+
+macro_rules! number {
+    ($($t:ty)*) => {
+        $(
+            /// Surrounding whitespaces are ignored.
+            impl Value for $t {
+                type Input = String;
+
+                fn parse(input: &String) -> Result<Self, String> {
+                    input.trim().parse().map_err(|_| "must be a number".to_string())
+                }
+
+                fn is_blank(input: &String) -> bool {
+                    input.trim().is_empty()
+                }
+            }
+        )*
+    };
+}
+
+number!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+/// A rule values must follow.
+#[derive(Debug, Clone)]
+pub enum Validator<T> {
+    /// The input must not be blank.
+    Required,
+    /// The value, as displayed, must match the regular expression, which isn't implicitly
+    /// anchored.
+    #[cfg(feature = "regex")]
+    Pattern(Regex),
+    /// The value must be within the range.
+    Range(RangeInclusive<T>),
+    /// The value must be accepted by the function, otherwise it returns the error message.
+    Custom(fn(&T) -> Result<(), String>),
+}
+
+impl<T: Value> Validator<T> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match self {
+            Validator::Required => Ok(()),
+
+            #[cfg(feature = "regex")]
+            Validator::Pattern(r) if r.is_match(&value.to_string()) => Ok(()),
+            #[cfg(feature = "regex")]
+            Validator::Pattern(r) => Err(format!("must match `{}`", r)),
+
+            Validator::Range(r) if r.contains(value) => Ok(()),
+            Validator::Range(r) => Err(format!("must be between {} and {}", r.start(), r.end())),
+
+            Validator::Custom(f) => f(value),
+        }
+    }
+}
+
+/// Something that may be invalid, such as a [`Field`].
+pub trait Validate {
+    /// The error messages, which are empty if valid.
+    fn errors(&self) -> Vec<String>;
+
+    /// Whether there are no errors.
+    fn is_valid(&self) -> bool {
+        self.errors().is_empty()
+    }
+}
+
+/// Whether every one of `fields` is valid.
+pub fn is_valid(fields: &[&dyn Validate]) -> bool {
+    fields.iter().all(|f| f.is_valid())
+}
+
+/// The input of a control along with the validators of the value parsed from it.
+#[derive(Debug, Clone)]
+pub struct Field<T: Value> {
+    label: String,
+    input: T::Input,
+    validators: Vec<Validator<T>>,
+    touched: bool,
+}
+
+impl<T: Value> Field<T> {
+    /// A field with the default input and no validators.
+    pub fn new(label: impl Into<String>) -> Self {
+        Field {
+            label: label.into(),
+            input: Default::default(),
+            validators: Vec::new(),
+            touched: false,
+        }
+    }
+
+    /// Appends `validator`.
+    pub fn with(mut self, validator: Validator<T>) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn input(&self) -> &T::Input {
+        &self.input
+    }
+
+    /// Replaces the input, typically with the value of an event, and touches the field.
+    pub fn set(&mut self, input: T::Input) {
+        self.input = input;
+        self.touched = true;
+    }
+
+    /// Shows error messages even if the input hasn't been edited yet.
+    pub fn touch(&mut self) {
+        self.touched = true;
+    }
+
+    /// Whether error messages are shown.
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    /// Parses and validates the input.
+    ///
+    /// A blank input that is required fails with a single error message, otherwise failing to
+    /// parse does, otherwise the messages of every validator that fails are returned in order.
+    pub fn value(&self) -> Result<T, Vec<String>> {
+        let required = self
+            .validators
+            .iter()
+            .any(|v| matches!(v, Validator::Required));
+
+        if required && T::is_blank(&self.input) {
+            return Err(vec!["is required".into()]);
+        }
+
+        let value = T::parse(&self.input).map_err(|e| vec![e])?;
+        let errors: Vec<_> = self
+            .validators
+            .iter()
+            .filter_map(|v| v.check(&value).err())
+            .collect();
+
+        match errors.is_empty() {
+            true => Ok(value),
+            false => Err(errors),
+        }
+    }
+
+    fn view<A>(&self, controls: Vec<Widget<'static, A>>) -> Widget<'static, A> {
+        let errors = match self.touched {
+            true => self.errors(),
+            false => Vec::new(),
+        };
+
+        widget!(Column { style: "field" } [
+            @{ controls }
+            @for content in errors { Text { content, style: "error" } }
+        ])
+    }
+
+    fn style(&self) -> &'static str {
+        match self.touched && !self.is_valid() {
+            true => "invalid",
+            false => "",
+        }
+    }
+}
+
+impl<T: Value<Input = String>> Field<T> {
+    /// Renders the label, an [`Entry`] bound to the input and the error messages, if shown.
+    ///
+    /// The [`Entry`] is styled `"invalid"` while error messages are shown.
+    pub fn entry<A>(&self, handler: fn(&Entry<A>, &Entered) -> A) -> Widget<'static, A> {
+        self.view(vec![
+            widget!(Text {
+                content: self.label.clone()
+            }),
+            widget!(Entry {
+                value: self.input.clone(),
+                handler,
+                style: self.style(),
+            }),
+        ])
+    }
+}
+
+impl<T: Value<Input = bool>> Field<T> {
+    /// Renders a [`Checkbox`] labeled after the field and bound to the input, followed by the
+    /// error messages, if shown.
+    ///
+    /// The [`Checkbox`] is styled `"invalid"` while error messages are shown.
+    pub fn checkbox<A>(&self, handler: fn(&Checkbox<A>, &Toggled) -> A) -> Widget<'static, A> {
+        self.view(vec![widget!(Checkbox {
+            label: self.label.clone(),
+            value: self.input,
+            handler,
+            style: self.style(),
+        })])
+    }
+}
+
+impl<T: Value> Validate for Field<T> {
+    fn errors(&self) -> Vec<String> {
+        self.value().err().unwrap_or_default()
+    }
+}
+
+/// Renders a [`Button`] that is disabled unless every one of `fields` is valid, so that the
+/// action of `handler` is only produced for valid forms.
+pub fn submit<A>(
+    label: impl Into<String>,
+    fields: &[&dyn Validate],
+    handler: fn(&Button<A>, &Clicked) -> A,
+) -> Widget<'static, A> {
+    widget!(Button {
+        label: label.into(),
+        handler,
+        disabled: !is_valid(fields),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snapshot::Dump;
+    use proptest::prelude::*;
+
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    enum Action {
+        Edit(String),
+        Toggle(bool),
+        Submit,
+    }
+
+    const ENTERED: fn(&Entry<Action>, &Entered) -> Action = |_, e| Action::Edit(e.value.clone());
+    const TOGGLED: fn(&Checkbox<Action>, &Toggled) -> Action = |_, e| Action::Toggle(e.value);
+    const CLICKED: fn(&Button<Action>, &Clicked) -> Action = |_, _| Action::Submit;
+
+    fn field<T: Value>(input: T::Input, validator: Validator<T>) -> Field<T> {
+        let mut field = Field::new("field").with(validator);
+        field.set(input);
+        field
+    }
+
+    proptest! {
+        #[test]
+        fn blank_inputs_are_required(input in "\\s*", n: i32) {
+            assert_eq!(field::<String>(input.clone(), Validator::Required).value(), Err(vec!["is required".into()]));
+            assert_eq!(field::<i32>(input.clone(), Validator::Required).value(), Err(vec!["is required".into()]));
+            assert_eq!(field::<String>(input.clone(), Validator::Custom(|_| Ok(()))).value(), Ok(input));
+            assert_eq!(field::<i32>(format!(" {} ", n), Validator::Required).value(), Ok(n));
+            assert_eq!(field::<bool>(false, Validator::Required).errors(), vec!["is required".to_string()]);
+            assert!(field::<bool>(true, Validator::Required).is_valid());
+        }
+
+        #[test]
+        fn numbers_are_parsed(input in "[a-z]+") {
+            assert_eq!(field::<u8>(input.clone(), Validator::Required).value(), Err(vec!["must be a number".into()]));
+            assert_eq!(field::<f64>(input, Validator::Required).value(), Err(vec!["must be a number".into()]));
+        }
+
+        #[test]
+        fn values_within_range(n: i32, a: i32, b: i32) {
+            let (a, b) = (a.min(b), a.max(b));
+            let f = field(n.to_string(), Validator::Range(a..=b));
+
+            match (a..=b).contains(&n) {
+                true => assert_eq!(f.value(), Ok(n)),
+                false => assert_eq!(f.errors(), vec![format!("must be between {} and {}", a, b)]),
+            }
+        }
+
+        #[test]
+        fn every_failing_validator_is_reported(n in 10..100u32) {
+            let even: fn(&u32) -> Result<(), String> = |n| match n % 2 {
+                0 => Ok(()),
+                _ => Err("must be even".into()),
+            };
+
+            let f = field(n.to_string(), Validator::Range(0..=9)).with(Validator::Custom(even));
+
+            match n % 2 {
+                0 => assert_eq!(f.errors(), vec!["must be between 0 and 9".to_string()]),
+                _ => assert_eq!(f.errors(), vec!["must be between 0 and 9".to_string(), "must be even".into()]),
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "regex")]
+        fn values_matching_patterns(input in "[0-9a-z]{0,8}") {
+            let digits = Regex::new("^[0-9]+$").unwrap();
+            let f = field::<String>(input.clone(), Validator::Pattern(digits));
+
+            match input.chars().all(|c| c.is_ascii_digit()) && !input.is_empty() {
+                true => assert!(f.is_valid()),
+                false => assert_eq!(f.errors(), vec!["must match `^[0-9]+$`".to_string()]),
+            }
+        }
+
+        #[test]
+        fn errors_are_shown_next_to_touched_fields(input in "\\s*", label: String) {
+            let mut name = Field::<String>::new(label.clone()).with(Validator::Required);
+            name.input = input.clone();
+
+            assert_eq!(name.entry(ENTERED), widget!(Column { style: "field" } [
+                Text { content: label.clone() }
+                Entry { value: input.clone(), handler: ENTERED }
+            ]));
+
+            name.touch();
+
+            assert_eq!(name.entry(ENTERED), widget!(Column { style: "field" } [
+                Text { content: label.clone() }
+                Entry { value: input.clone(), handler: ENTERED, style: "invalid" }
+                Text { content: "is required", style: "error" }
+            ]));
+
+            let mut terms = Field::<bool>::new(label.clone()).with(Validator::Required);
+            terms.touch();
+
+            assert_eq!(terms.checkbox(TOGGLED), widget!(Column { style: "field" } [
+                Checkbox { label: label.clone(), handler: TOGGLED, style: "invalid" }
+                Text { content: "is required", style: "error" }
+            ]));
+
+            terms.set(true);
+
+            assert_eq!(terms.checkbox(TOGGLED), widget!(Column { style: "field" } [
+                Checkbox { label, value: true, handler: TOGGLED }
+            ]));
+        }
+
+        #[test]
+        fn submit_only_when_every_field_is_valid(name: String, age in 0..200u8, terms: bool) {
+            let name = field::<String>(name, Validator::Required);
+            let age = field::<u8>(age.to_string(), Validator::Range(18..=99));
+            let terms = field::<bool>(terms, Validator::Required);
+
+            let valid = name.is_valid() && age.is_valid() && terms.is_valid();
+            let ui: Widget<Action> = widget!(Column [
+                @{ name.entry(ENTERED) }
+                @{ age.entry(ENTERED) }
+                @{ terms.checkbox(TOGGLED) }
+                @{ submit("Submit", &[&name, &age, &terms], CLICKED) }
+            ]);
+
+            assert_eq!(is_valid(&[&name, &age, &terms]), valid);
+            assert_eq!(ui.dispatch(vec![3usize], &Clicked::default().into()), Some(Action::Submit).filter(|_| valid));
+        }
+    }
+
+    #[test]
+    fn dump() {
+        let mut age = Field::<u8>::new("Age").with(Validator::Range(18..=99));
+        age.set("12".into());
+
+        assert_eq!(
+            Dump(&age.entry(ENTERED)).to_string(),
+            "Column.field\n  \
+               Text(\"Age\")\n  \
+               Entry.invalid(\"12\", handler)\n  \
+               Text.error(\"must be between 18 and 99\")\n"
+        );
+    }
+}
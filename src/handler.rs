@@ -1,4 +1,6 @@
-use crate::{event::Event, widget::Widget, Kind, Variant};
+use crate::event::{Changed, Event, FromEvent};
+use crate::lens::{Bindable, Binding, Lens, Set};
+use crate::{widget::Widget, Kind, Variant};
 
 /// An event handler.
 #[derive(derivative::Derivative)]
@@ -12,6 +14,7 @@ where
     B(fn(&Widget<A>, &E) -> A),
     C(fn(&W, &Event) -> A),
     D(fn(&Widget<A>, &Event) -> A),
+    Bound(Binding<E, A>),
 }
 
 impl<W, E, A> GenericHandler<W, E, A>
@@ -19,6 +22,8 @@ where
     for<'a> W: Kind<Widget<'a, A>>,
     for<'a> E: Kind<Event<'a>>,
 {
+    /// The function pointer, or the getter of the lens of [`GenericHandler::Bound`], which only
+    /// identifies the handler for formatting purposes.
     fn decay(&self) -> *const () {
         use GenericHandler::*;
         match *self {
@@ -26,6 +31,7 @@ where
             B(f) => f as *const (),
             C(f) => f as *const (),
             D(f) => f as *const (),
+            Bound(b) => b.id().0 as *const (),
         }
     }
 }
@@ -65,7 +71,12 @@ where
     for<'a> E: Kind<Event<'a>>,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.decay() == other.decay()
+        use GenericHandler::*;
+        match (self, other) {
+            (Bound(a), Bound(b)) => a.id() == b.id(),
+            (Bound(_), _) | (_, Bound(_)) => false,
+            (a, b) => a.decay() == b.decay(),
+        }
     }
 }
 
@@ -77,7 +88,10 @@ where
     for<'a> E: Kind<Event<'a>>,
 {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.decay().hash(state);
+        match self {
+            GenericHandler::Bound(b) => b.id().hash(state),
+            f => f.decay().hash(state),
+        }
     }
 }
 
@@ -124,6 +138,7 @@ where
             Handler(B(f)) => f(&widget.into(), event),
             Handler(C(f)) => f(widget, &event.into()),
             Handler(D(f)) => f(&widget.into(), &event.into()),
+            Handler(Bound(b)) => b.bind(event),
         }
     }
 }
@@ -168,10 +183,24 @@ where
     }
 }
 
+/// Binds the widget to the state a [`Lens`] focuses on, see [`Set`].
+impl<W, S, T, A> From<&'static Lens<S, T>> for Handler<W, Changed<T>, A>
+where
+    for<'a> W: Variant<Widget<'a, A>>,
+    for<'a> Changed<T>: Variant<Event<'a>>,
+    S: 'static,
+    T: Bindable,
+    A: From<Set<S>>,
+{
+    fn from(lens: &'static Lens<S, T>) -> Self {
+        Handler(GenericHandler::Bound(Binding::new(lens)))
+    }
+}
+
 /// An optional event handler.
 ///
 /// Semantically equivalent to `Option<Handler<W, E, A>>`,
-/// but more ergonomic to use with the [`widget!`] macro.
+/// but more ergonomic to use with the [`widget!`](macro@crate::widget) macro.
 ///
 /// In particular, [`OptionalHandler`] implements [`From<H: Into<Handler>>`](#impl-From<H>).
 #[derive(derivative::Derivative)]
@@ -194,6 +223,51 @@ where
     None,
 }
 
+impl<W, E, A> OptionalHandler<W, E, A>
+where
+    for<'a> W: Kind<Widget<'a, A>>,
+    for<'a> E: Kind<Event<'a>>,
+    for<'a> &'a W: Into<Widget<'a, A>>,
+    for<'a> &'a E: Into<Event<'a>>,
+{
+    pub fn handle(&self, widget: &W, event: &E) -> Option<A> {
+        match self {
+            OptionalHandler::Some(h) => Some(h.handle(widget, event)),
+            OptionalHandler::None => None,
+        }
+    }
+}
+
+/// Routes events of any kind to handlers of a specific kind of event.
+pub trait HandleEvent<W, A> {
+    /// Handles `event` if it is of the kind expected by the handler.
+    fn handle_event(&self, widget: &W, event: &Event) -> Option<A>;
+}
+
+impl<W, E, A> HandleEvent<W, A> for Handler<W, E, A>
+where
+    for<'a> W: Kind<Widget<'a, A>>,
+    E: FromEvent,
+    for<'a> &'a W: Into<Widget<'a, A>>,
+    for<'a> &'a E: Into<Event<'a>>,
+{
+    fn handle_event(&self, widget: &W, event: &Event) -> Option<A> {
+        Some(self.handle(widget, event.downcast_ref()?))
+    }
+}
+
+impl<W, E, A> HandleEvent<W, A> for OptionalHandler<W, E, A>
+where
+    for<'a> W: Kind<Widget<'a, A>>,
+    E: FromEvent,
+    for<'a> &'a W: Into<Widget<'a, A>>,
+    for<'a> &'a E: Into<Event<'a>>,
+{
+    fn handle_event(&self, widget: &W, event: &Event) -> Option<A> {
+        self.handle(widget, event.downcast_ref()?)
+    }
+}
+
 impl<H, W, E, A> From<H> for OptionalHandler<W, E, A>
 where
     for<'a> W: Kind<Widget<'a, A>>,
@@ -270,6 +344,28 @@ mod tests {
             assert_eq!(handler.handle(&w, &e), Action);
         }
 
+        #[test]
+        fn optional_handle(w: Widget<Action>, e: Event, handler: OptionalHandler<Widget<_>, Event, Action>) {
+            match handler {
+                OptionalHandler::Some(_) => assert_eq!(handler.handle(&w, &e), Some(Action)),
+                OptionalHandler::None => assert_eq!(handler.handle(&w, &e), None),
+            }
+        }
+
+        #[test]
+        fn handle_event(w: Widget<Action>, e: Event, handler: OptionalHandler<Widget<_>, Clicked, Action>) {
+            let expected = match (&handler, &e) {
+                (OptionalHandler::Some(_), Event::Clicked(_)) => Some(Action),
+                _ => None,
+            };
+
+            assert_eq!(handler.handle_event(&w, &e), expected);
+
+            if let OptionalHandler::Some(h) = handler {
+                assert_eq!(h.handle_event(&w, &e), expected);
+            }
+        }
+
         #[test]
         fn debug(handler: Handler<Widget<_>, Event, Action>) {
             assert_eq!(format!("{:?}", handler), format!("Handler({:p})", handler.0));
@@ -0,0 +1,275 @@
+use crate::{runtime, Application, Command, Subscription, Widget};
+
+/// Time-travel for an [`Application`], which records every action along with a snapshot of
+/// the state it produced.
+///
+/// A `History` is itself an [`Application`] and is meant to be driven by the
+/// [`Runtime`](crate::Runtime) in place of the application it wraps.
+/// Updating the application after [`History::undo`] or [`History::jump_to`] discards the
+/// actions that could otherwise be redone.
+///
+/// Since the actions produced by [`Command`]s and [`Subscription`]s are recorded as well,
+/// the [log](History::log) is enough to reproduce the present state through [`History::replay`].
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::{Application, Command, History, Widget, widget::Column};
+///
+/// #[derive(Clone, Default)]
+/// struct Counter(i32);
+///
+/// impl Application for Counter {
+///     type Action = i32;
+///
+///     fn update(&mut self, n: i32) -> Command<i32> {
+///         self.0 += n;
+///         Command::none()
+///     }
+///
+///     fn view(&self) -> Widget<'_, i32> {
+///         Column::default().into()
+///     }
+/// }
+///
+/// let mut history = History::new(Counter::default());
+/// history.update(1);
+/// history.update(2);
+/// assert_eq!(history.present().0, 3);
+///
+/// history.undo();
+/// assert_eq!(history.present().0, 1);
+///
+/// history.redo();
+/// assert_eq!(history.present().0, 3);
+///
+/// let replayed = History::replay(Counter::default(), history.log().to_vec());
+/// assert_eq!(replayed.present().0, 3);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct History<S, A> {
+    states: Vec<S>,
+    actions: Vec<A>,
+    cursor: usize,
+}
+
+impl<S, A> History<S, A> {
+    /// Starts recording from the `initial` state.
+    pub fn new(initial: S) -> Self {
+        History {
+            states: vec![initial],
+            actions: Vec::new(),
+            cursor: 0,
+        }
+    }
+
+    /// The present state.
+    pub fn present(&self) -> &S {
+        &self.states[self.cursor]
+    }
+
+    /// The number of actions that led to the present state.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// The number of actions recorded, including those that may be redone.
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// Whether no actions have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// The actions that led to the present state, in order.
+    pub fn log(&self) -> &[A] {
+        &self.actions[..self.cursor]
+    }
+
+    /// Whether there are actions that may be undone.
+    pub fn can_undo(&self) -> bool {
+        self.cursor > 0
+    }
+
+    /// Whether there are actions that may be redone.
+    pub fn can_redo(&self) -> bool {
+        self.cursor < self.actions.len()
+    }
+
+    /// Reverts the last action, returns `false` if there's nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.can_undo() && self.jump_to(self.cursor - 1)
+    }
+
+    /// Reapplies the last action undone, returns `false` if there's nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        self.can_redo() && self.jump_to(self.cursor + 1)
+    }
+
+    /// Moves to the state produced by the first `n` actions, returns `false` if out of bounds.
+    pub fn jump_to(&mut self, n: usize) -> bool {
+        if n < self.states.len() {
+            self.cursor = n;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Discards the recording and returns the present state.
+    pub fn into_present(mut self) -> S {
+        self.states.swap_remove(self.cursor)
+    }
+}
+
+impl<App: Application + Clone> History<App, App::Action> {
+    /// Reproduces a recording by applying `actions` in order to the `initial` state.
+    ///
+    /// [`Command`]s returned by the application are dropped without being performed.
+    pub fn replay(initial: App, actions: impl IntoIterator<Item = App::Action>) -> Self
+    where
+        App::Action: Clone,
+    {
+        let mut history = History::new(initial);
+
+        for action in actions {
+            drop(history.update(action));
+        }
+
+        history
+    }
+}
+
+impl<App> Application for History<App, App::Action>
+where
+    App: Application + Clone,
+    App::Action: Clone,
+{
+    type Action = App::Action;
+
+    fn update(&mut self, action: Self::Action) -> Command<Self::Action> {
+        self.states.truncate(self.cursor + 1);
+        self.actions.truncate(self.cursor);
+
+        let mut state = self.present().clone();
+        let command = runtime::apply(&mut state, action.clone());
+
+        self.states.push(state);
+        self.actions.push(action);
+        self.cursor += 1;
+
+        command
+    }
+
+    fn view(&self) -> Widget<'_, Self::Action> {
+        self.present().view()
+    }
+
+    fn subscriptions(&self) -> Subscription<Self::Action> {
+        self.present().subscriptions()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::Column;
+    use proptest::prelude::*;
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    struct Sum(Vec<i32>);
+
+    impl Application for Sum {
+        type Action = i32;
+
+        fn update(&mut self, n: i32) -> Command<i32> {
+            self.0.push(n);
+            Command::perform(async move { n })
+        }
+
+        fn view(&self) -> Widget<'_, i32> {
+            Column::default().into()
+        }
+    }
+
+    fn record(ns: &[i32]) -> History<Sum, i32> {
+        let mut history = History::new(Sum::default());
+
+        for &n in ns {
+            assert_eq!(history.update(n).len(), 1);
+        }
+
+        history
+    }
+
+    proptest! {
+        #[test]
+        fn update_records_actions(ns: Vec<i32>) {
+            let history = record(&ns);
+
+            assert_eq!(history.present(), &Sum(ns.clone()));
+            assert_eq!(history.log(), &*ns);
+            assert_eq!(history.len(), ns.len());
+            assert_eq!(history.cursor(), ns.len());
+            assert!(!history.can_redo());
+        }
+
+        #[test]
+        fn undo_and_redo(ns: Vec<i32>) {
+            let mut history = record(&ns);
+
+            for i in (0..ns.len()).rev() {
+                assert!(history.undo());
+                assert_eq!(history.present(), &Sum(ns[..i].to_vec()));
+            }
+
+            assert!(!history.undo());
+            assert_eq!(history.present(), &Sum::default());
+
+            for i in 1..=ns.len() {
+                assert!(history.redo());
+                assert_eq!(history.present(), &Sum(ns[..i].to_vec()));
+            }
+
+            assert!(!history.redo());
+        }
+
+        #[test]
+        fn jump_to(ns: Vec<i32>, n: usize) {
+            let mut history = record(&ns);
+            let n = n % (ns.len() + 1);
+
+            assert!(history.jump_to(n));
+            assert_eq!(history.present(), &Sum(ns[..n].to_vec()));
+            assert_eq!(history.log(), &ns[..n]);
+            assert_eq!(history.len(), ns.len());
+
+            assert!(!history.jump_to(ns.len() + 1));
+            assert_eq!(history.cursor(), n);
+        }
+
+        #[test]
+        fn update_discards_redo(ns: Vec<i32>, n: usize, m: i32) {
+            let mut history = record(&ns);
+            let n = n % (ns.len() + 1);
+
+            history.jump_to(n);
+            history.update(m);
+
+            let mut expected = ns[..n].to_vec();
+            expected.push(m);
+
+            assert_eq!(history.log(), &*expected);
+            assert_eq!(history.len(), n + 1);
+            assert!(!history.can_redo());
+            assert_eq!(history.into_present(), Sum(expected));
+        }
+
+        #[test]
+        fn replay(ns: Vec<i32>) {
+            let history = record(&ns);
+            assert_eq!(History::replay(Sum::default(), history.log().to_vec()), history);
+        }
+    }
+}
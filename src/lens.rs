@@ -0,0 +1,247 @@
+use crate::event::Changed;
+use std::any::Any;
+use std::hash::{Hash, Hasher};
+
+/// Focuses on a part `T` of some state `S`, such as a field of a struct.
+///
+/// Lenses bind widgets to the state of an [`Application`](crate::Application): bound widgets,
+/// such as [`Entry::bound`](crate::widget::Entry::bound), read their value through the lens and
+/// produce [`Set`] actions that the [`Runtime`](crate::Runtime) applies without going through
+/// [`Application::update`](crate::Application::update).
+///
+/// Since handlers are plain function pointers, widgets refer to lenses through `&'static`
+/// references, which are conveniently declared as constants.
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::{event::Entered, lens, widget::Entry, Lens, Set};
+///
+/// #[derive(Default)]
+/// struct Profile {
+///     name: String,
+/// }
+///
+/// const NAME: Lens<Profile, String> = lens!(Profile, name);
+///
+/// let mut profile = Profile::default();
+/// let entry: Entry<Set<Profile>> = Entry::bound(&NAME, &profile);
+/// let action = entry.handler.handle(&entry, &Entered { value: "Alice".into() });
+///
+/// action.unwrap().apply(&mut profile);
+/// assert_eq!(profile.name, "Alice");
+/// ```
+#[derive(derivative::Derivative)]
+#[derivative(Debug(bound = ""), Copy(bound = ""), Clone(bound = ""))]
+pub struct Lens<S, T> {
+    get: fn(&S) -> &T,
+    get_mut: fn(&mut S) -> &mut T,
+}
+
+impl<S, T> Lens<S, T> {
+    pub const fn new(get: fn(&S) -> &T, get_mut: fn(&mut S) -> &mut T) -> Self {
+        Lens { get, get_mut }
+    }
+
+    pub fn get<'s>(&self, state: &'s S) -> &'s T {
+        (self.get)(state)
+    }
+
+    pub fn get_mut<'s>(&self, state: &'s mut S) -> &'s mut T {
+        (self.get_mut)(state)
+    }
+
+    /// Replaces the part of `state` this lens focuses on.
+    pub fn set(&self, state: &mut S, value: T) {
+        *self.get_mut(state) = value;
+    }
+
+    fn decay(&self) -> (*const (), *const ()) {
+        (self.get as *const (), self.get_mut as *const ())
+    }
+}
+
+impl<S, T> Eq for Lens<S, T> {}
+
+impl<S, T> PartialEq for Lens<S, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.decay() == other.decay()
+    }
+}
+
+impl<S, T> Hash for Lens<S, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.decay().hash(state);
+    }
+}
+
+/// Declares a [`Lens`] that focuses on a field of a struct, possibly nested.
+///
+/// `lens!(Profile, address.city)` focuses on `profile.address.city`.
+#[macro_export]
+macro_rules! lens {
+    ($s:ty, $($f:tt).+) => {
+        $crate::Lens::new(|s: &$s| &s.$($f).+, |s: &mut $s| &mut s.$($f).+)
+    };
+}
+
+/// An action that replaces the part of some state `S` a [`Lens`] focuses on.
+///
+/// The [`Runtime`](crate::Runtime) applies the [`Set`] actions that an
+/// [`Application`](crate::Application) extracts through
+/// [`Application::set`](crate::Application::set).
+#[derive(derivative::Derivative)]
+#[derivative(
+    Debug(bound = ""),
+    Clone(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = ""),
+    Hash(bound = "")
+)]
+pub enum Set<S> {
+    Text(Lens<S, String>, String),
+    Flag(Lens<S, bool>, bool),
+}
+
+impl<S> Set<S> {
+    pub fn apply(self, state: &mut S) {
+        match self {
+            Set::Text(lens, value) => lens.set(state, value),
+            Set::Flag(lens, value) => lens.set(state, value),
+        }
+    }
+}
+
+/// A value widgets can be bound to through a [`Lens`].
+pub trait Bindable: 'static + Clone {
+    fn set<S>(lens: Lens<S, Self>, value: Self) -> Set<S>;
+}
+
+impl Bindable for String {
+    fn set<S>(lens: Lens<S, Self>, value: Self) -> Set<S> {
+        Set::Text(lens, value)
+    }
+}
+
+impl Bindable for bool {
+    fn set<S>(lens: Lens<S, Self>, value: Self) -> Set<S> {
+        Set::Flag(lens, value)
+    }
+}
+
+/// A [`Lens`] whose type is erased, along with the function that binds it to events.
+#[derive(derivative::Derivative)]
+#[derivative(Copy(bound = ""), Clone(bound = ""))]
+pub(crate) struct Binding<E, A> {
+    lens: &'static (dyn Any + Send + Sync),
+    id: (usize, usize),
+    bind: fn(&(dyn Any + Send + Sync), &E) -> A,
+}
+
+impl<T: Bindable, A> Binding<Changed<T>, A> {
+    pub(crate) fn new<S: 'static>(lens: &'static Lens<S, T>) -> Self
+    where
+        A: From<Set<S>>,
+    {
+        let (get, get_mut) = lens.decay();
+
+        Binding {
+            lens,
+            id: (get as usize, get_mut as usize),
+            bind: |lens, event| {
+                let lens = lens
+                    .downcast_ref::<Lens<S, T>>()
+                    .expect("lens type mismatch");
+                T::set(*lens, event.value.clone()).into()
+            },
+        }
+    }
+}
+
+impl<E, A> Binding<E, A> {
+    pub(crate) fn bind(&self, event: &E) -> A {
+        (self.bind)(self.lens, event)
+    }
+
+    /// Identifies the lens for the purposes of comparison and hashing.
+    pub(crate) fn id(&self) -> (usize, usize) {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{event::Entered, widget::Entry, OptionalHandler};
+    use proptest::prelude::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    struct Address {
+        city: String,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    struct Profile {
+        name: String,
+        address: Address,
+        public: bool,
+    }
+
+    const NAME: Lens<Profile, String> = lens!(Profile, name);
+    const CITY: Lens<Profile, String> = lens!(Profile, address.city);
+    const PUBLIC: Lens<Profile, bool> = lens!(Profile, public);
+
+    /// Reads the name, but writes the city.
+    const MIXED: Lens<Profile, String> = Lens::new(NAME.get, CITY.get_mut);
+
+    fn hash<T: Hash>(t: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    proptest! {
+        #[test]
+        fn get_and_set(mut profile: (String, String, bool), name: String, city: String) {
+            let mut p = Profile {
+                name: profile.0.clone(),
+                address: Address { city: profile.1.clone() },
+                public: profile.2,
+            };
+
+            assert_eq!(NAME.get(&p), &profile.0);
+            assert_eq!(CITY.get(&p), &profile.1);
+            assert_eq!(PUBLIC.get(&p), &profile.2);
+
+            NAME.set(&mut p, name.clone());
+            Set::Text(CITY, city.clone()).apply(&mut p);
+            Set::Flag(PUBLIC, !profile.2).apply(&mut p);
+
+            profile = (name, city, !profile.2);
+            assert_eq!((p.name, p.address.city, p.public), profile);
+        }
+
+        #[test]
+        fn bind(value: String, flag: bool) {
+            let set: Set<Profile> = Binding::new(&NAME).bind(&Changed { value: value.clone() });
+            assert_eq!(set, Set::Text(NAME, value));
+
+            let set: Set<Profile> = Binding::new(&PUBLIC).bind(&Changed { value: flag });
+            assert_eq!(set, Set::Flag(PUBLIC, flag));
+        }
+    }
+
+    #[test]
+    fn lenses_compare_by_their_functions() {
+        assert_eq!(NAME, NAME);
+        assert_eq!(hash(&NAME), hash(&NAME));
+        assert_ne!(NAME, CITY);
+        assert_ne!(NAME, MIXED);
+
+        let handler = |lens| OptionalHandler::<Entry<Set<Profile>>, Entered, _>::from(lens);
+        assert_eq!(handler(&NAME), handler(&NAME));
+        assert_eq!(hash(&handler(&NAME)), hash(&handler(&NAME)));
+        assert_ne!(handler(&NAME), handler(&CITY));
+        assert_ne!(handler(&NAME), handler(&MIXED));
+    }
+}
@@ -1,17 +1,58 @@
+extern crate self as oxidizer;
+
+mod command;
+mod component;
+mod executor;
 mod handler;
+mod history;
+mod lens;
+mod memo;
 mod path;
+mod runtime;
+mod subscription;
 mod sugar;
 
-pub use handler::{Handler, OptionalHandler};
+pub use command::{BoxFuture, Command};
+pub use component::{AnyComponent, Component};
+pub use executor::{Executor, ThreadExecutor};
+pub use handler::{HandleEvent, Handler, OptionalHandler};
+pub use history::History;
+pub use lens::{Bindable, Lens, Set};
+pub use memo::Memo;
+pub use runtime::{Application, Runtime};
+pub use subscription::{Sink, Subscription};
+
+pub mod a11y;
+
+pub mod diff;
+pub use diff::diff;
 
 pub mod event;
 pub use event::Event;
 
+pub mod form;
+
+pub mod lint;
+
+pub mod parser;
+
+pub mod session;
+
+pub mod snapshot;
+
+pub mod style;
+
 pub mod widget;
 pub use widget::Widget;
 
+/// Derives [`CustomWidget`](widget::CustomWidget), see its documentation for an example.
+pub use oxidizer_derive::Widget;
+
 pub use path::TreePath;
 
+#[doc(hidden)]
+pub use sugar::splice;
+
 /// Marker trait used to denote an abstract _kind_.
 ///
 /// A [`Kind`] and its [`Variant`]s form a closed polymorphic group.
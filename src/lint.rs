@@ -0,0 +1,337 @@
+//! Detection of common mistakes in widget trees.
+//!
+//! [`lint`] walks a widget tree and reports a [`Diagnostic`] for every widget that is likely to be
+//! a mistake, such as controls that can't be told apart by assistive technologies or that ignore
+//! user input.
+//! It is meant to be used from unit tests.
+//!
+//! ```rust
+//! use oxidizer::{lint::lint, widget, Event, Widget};
+//!
+//! let handler: fn(&Widget<_>, &Event) = |_, _| ();
+//! let ui: Widget<()> = widget!(Row [
+//!     Text { content: "Todo" }
+//!     Entry { handler }
+//!     Button { label: "Add", handler }
+//! ]);
+//! assert!(lint(&ui).is_empty());
+//! ```
+
+use crate::{path::DisplayPath, widget::*, OptionalHandler};
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+
+/// The depth beyond which widgets are reported as [`Lint::DeepNesting`].
+pub const MAX_DEPTH: usize = 32;
+
+/// A kind of mistake.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Lint {
+    /// A [`Button`] or [`Checkbox`] whose label is empty.
+    EmptyLabel,
+    /// An [`Entry`] that doesn't follow a sibling [`Text`] to describe it.
+    UnlabeledEntry,
+    /// A [`Button`], [`Entry`], [`Checkbox`], [`TreeView`] or [`Tabs`] without any handler, which
+    /// ignores user input, unless it or an ancestor is disabled or it is read-only, or an open
+    /// [`Modal`] without a handler, which can't be dismissed.
+    MissingHandler,
+    /// A [`Stateful`] widget with the same non-empty key as a preceding sibling,
+    /// which shares its local state.
+    DuplicateKey(String),
+    /// A widget nested deeper than [`MAX_DEPTH`], whose descendants aren't reported again.
+    DeepNesting,
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Lint::EmptyLabel => write!(f, "the label is empty"),
+            Lint::UnlabeledEntry => write!(f, "the entry doesn't follow a label"),
+            Lint::MissingHandler => write!(f, "the widget has no handler"),
+            Lint::DuplicateKey(k) => write!(f, "the key {:?} is used by a preceding sibling", k),
+            Lint::DeepNesting => write!(f, "the widget is nested deeper than {}", MAX_DEPTH),
+        }
+    }
+}
+
+/// A [`Lint`] reported for the widget at `path`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Diagnostic {
+    pub path: Vec<usize>,
+    pub lint: Lint,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", DisplayPath(&self.path), self.lint)
+    }
+}
+
+/// Reports the mistakes found in `widget` and its descendants, in depth-first order.
+pub fn lint<A>(widget: &Widget<A>) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    visit(&mut Vec::new(), widget, None, false, &mut diagnostics);
+    diagnostics
+}
+
+fn visit<A>(
+    path: &mut Vec<usize>,
+    widget: &Widget<A>,
+    previous: Option<&Widget<A>>,
+    inherited: bool,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut report = |lint| {
+        diagnostics.push(Diagnostic {
+            path: path.clone(),
+            lint,
+        })
+    };
+
+    if path.len() > MAX_DEPTH {
+        return report(Lint::DeepNesting);
+    }
+
+    let disabled = inherited || widget.is_disabled();
+
+    match widget.resolve() {
+        Widget::Button(w) => {
+            if w.label.is_empty() {
+                report(Lint::EmptyLabel);
+            }
+
+            if !disabled && !has_handler(&w.handler) {
+                report(Lint::MissingHandler);
+            }
+        }
+
+        Widget::Entry(w) => {
+            if !previous.map_or(false, is_label) {
+                report(Lint::UnlabeledEntry);
+            }
+
+            if !disabled && !w.readonly && !has_handler(&w.handler) {
+                report(Lint::MissingHandler);
+            }
+        }
+
+        Widget::Checkbox(w) => {
+            if w.label.is_empty() {
+                report(Lint::EmptyLabel);
+            }
+
+            if !disabled && !has_handler(&w.handler) {
+                report(Lint::MissingHandler);
+            }
+        }
+
+        Widget::TreeView(w) => {
+            let handled = has_handler(&w.expand_handler)
+                || has_handler(&w.collapse_handler)
+                || has_handler(&w.select_handler);
+
+            if !disabled && !handled {
+                report(Lint::MissingHandler);
+            }
+        }
+
+        Widget::Tabs(w) if !disabled && w.handler.is_none() => report(Lint::MissingHandler),
+        Widget::Modal(w) if !disabled && w.open && w.handler.is_none() => {
+            report(Lint::MissingHandler)
+        }
+
+        _ => {}
+    }
+
+    let children = widget.children();
+    let mut keys = HashSet::new();
+
+    for (j, (i, child)) in widget.indices().zip(children).enumerate() {
+        path.push(i);
+
+        if let Widget::Stateful(s) = child.resolve() {
+            if !s.key.is_empty() && !keys.insert(&s.key) {
+                diagnostics.push(Diagnostic {
+                    path: path.clone(),
+                    lint: Lint::DuplicateKey(s.key.clone()),
+                });
+            }
+        }
+
+        let previous = j.checked_sub(1).map(|k| &children[k]);
+        visit(path, child, previous, disabled, diagnostics);
+        path.pop();
+    }
+}
+
+fn has_handler<W, E, A>(handler: &OptionalHandler<W, E, A>) -> bool
+where
+    for<'e> E: crate::Kind<crate::Event<'e>>,
+    for<'w> W: crate::Kind<Widget<'w, A>>,
+{
+    matches!(handler, OptionalHandler::Some(_))
+}
+
+fn is_label<A>(widget: &Widget<A>) -> bool {
+    match widget.resolve() {
+        Widget::Text(w) => !w.content.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{event::*, widget, Component};
+    use proptest::prelude::*;
+    use std::{convert::TryFrom, sync::Arc};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    struct Counter;
+
+    struct Never;
+
+    impl TryFrom<()> for Never {
+        type Error = ();
+
+        fn try_from(action: ()) -> Result<Self, ()> {
+            Err(action)
+        }
+    }
+
+    impl Component<()> for Counter {
+        type State = ();
+        type Message = Never;
+
+        fn update(&self, _: &mut (), _: Never) -> Option<()> {
+            None
+        }
+
+        fn view(&self, _: &()) -> Widget<'static, ()> {
+            widget!(Row)
+        }
+    }
+
+    const CLICKED: fn(&Button<()>, &Clicked) = |_, _| ();
+    const ENTERED: fn(&Entry<()>, &Entered) = |_, _| ();
+    const TOGGLED: fn(&Checkbox<()>, &Toggled) = |_, _| ();
+
+    fn diagnostic(path: Vec<usize>, lint: Lint) -> Diagnostic {
+        Diagnostic { path, lint }
+    }
+
+    proptest! {
+        #[test]
+        fn well_formed_trees_have_no_diagnostics(label in ".+", key in ".+") {
+            let ui: Widget<()> = widget!(Column [
+                Row [
+                    Text { content: label.clone() }
+                    Entry { handler: ENTERED }
+                    Button { label: label.clone(), handler: CLICKED }
+                ]
+                Checkbox { label: label.clone(), handler: TOGGLED }
+                Stateful { key: key.clone(), component: Counter }
+                Stateful { component: Counter }
+                Stateful { component: Counter }
+            ]);
+
+            assert!(lint(&ui).is_empty());
+            assert!(lint(&Widget::Shared(Arc::new(ui))).is_empty());
+        }
+
+        #[test]
+        fn empty_labels_and_missing_handlers(label in ".+") {
+            let ui: Widget<()> = widget!(Row [
+                Button { handler: CLICKED }
+                Checkbox { label: label.clone() }
+                Text { content: label.clone() }
+                Entry
+            ]);
+
+            assert_eq!(lint(&ui), vec![
+                diagnostic(vec![0], Lint::EmptyLabel),
+                diagnostic(vec![1], Lint::MissingHandler),
+                diagnostic(vec![3], Lint::MissingHandler),
+            ]);
+
+            let ui: Widget<()> = widget!(Row [
+                Button { label: label.clone(), disabled: true }
+                Checkbox { label: label.clone(), disabled: true }
+                Text { content: label.clone() }
+                Entry { disabled: true }
+                Text { content: label.clone() }
+                Entry { readonly: true }
+            ]);
+
+            assert!(lint(&ui).is_empty());
+        }
+
+        #[test]
+        fn disabled_ancestors_silence_missing_handlers(label in ".+") {
+            let ui: Widget<()> = widget!(Column [
+                Row { disabled: true } [
+                    Button { label: label.clone() }
+                    Column [ Checkbox { label: label.clone() } ]
+                ]
+                Button { label }
+            ]);
+
+            assert_eq!(lint(&ui), vec![diagnostic(vec![1], Lint::MissingHandler)]);
+        }
+
+        #[test]
+        fn entries_need_a_preceding_label(label in ".+") {
+            let ui: Widget<()> = widget!(Column [
+                Entry { handler: ENTERED }
+                Text { content: label.clone() }
+                Row [
+                    Button { label: label.clone(), handler: CLICKED }
+                    Entry { handler: ENTERED }
+                ]
+                Entry { handler: ENTERED }
+                Row [
+                    Text { content: label }
+                    Entry { handler: ENTERED }
+                ]
+            ]);
+
+            assert_eq!(lint(&ui), vec![
+                diagnostic(vec![0], Lint::UnlabeledEntry),
+                diagnostic(vec![2, 1], Lint::UnlabeledEntry),
+                diagnostic(vec![3], Lint::UnlabeledEntry),
+            ]);
+        }
+
+        #[test]
+        fn duplicate_keys_among_siblings(key in ".+") {
+            let ui: Widget<()> = widget!(Column [
+                Stateful { key: key.clone(), component: Counter }
+                Row [ Stateful { key: key.clone(), component: Counter } ]
+                Stateful { key: key.clone(), component: Counter }
+            ]);
+
+            assert_eq!(lint(&ui), vec![diagnostic(vec![2], Lint::DuplicateKey(key))]);
+        }
+
+        #[test]
+        fn deep_nesting_is_reported_once(extra in 1..8usize) {
+            let ui = (0..MAX_DEPTH + extra).fold(widget!(Button), |w: Widget<()>, _| {
+                widget!(Row [ @{ w } ])
+            });
+
+            assert_eq!(lint(&ui), vec![diagnostic(vec![0; MAX_DEPTH + 1], Lint::DeepNesting)]);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            diagnostic(vec![], Lint::EmptyLabel).to_string(),
+            ".: the label is empty"
+        );
+        assert_eq!(
+            diagnostic(vec![0, 2], Lint::DuplicateKey("a".into())).to_string(),
+            "0/2: the key \"a\" is used by a preceding sibling"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crate::widget::Widget;
+use std::hash::Hash;
+use std::{cell::RefCell, collections::HashMap, fmt, sync::Arc};
+
+/// A memoized view function.
+///
+/// [`Memo`] only calls the view function for properties it hasn't seen before, otherwise it
+/// reuses the widget built out of equal properties, so a single memo may serve every item of a
+/// list.
+/// Widgets stay cached until [`Memo::clear`] is called, which long-lived memos over properties
+/// that keep changing should do from time to time.
+///
+/// The widget is returned as [`Widget::Shared`], so [`diff`](crate::diff()) skips it without
+/// inspecting its contents while the properties are unchanged.
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::{*, widget::*};
+///
+/// let footer = Memo::new(|year: &u32| -> Widget<()> {
+///     widget!(Row [ Button { label: format!("© {}", year) } ])
+/// });
+///
+/// let a = footer.view(2020);
+/// let b = footer.view(2020);
+/// assert!(a.ptr_eq(&b));
+/// ```
+pub struct Memo<P, A: 'static> {
+    view: fn(&P) -> Widget<'static, A>,
+    cache: RefCell<HashMap<P, Arc<Widget<'static, A>>>>,
+}
+
+impl<P: Eq + Hash, A: 'static> Memo<P, A> {
+    pub fn new(view: fn(&P) -> Widget<'static, A>) -> Self {
+        Memo {
+            view,
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the widget built out of `props`, calling the view function only if necessary.
+    pub fn view(&self, props: P) -> Widget<'static, A> {
+        let view = self.view;
+        let mut cache = self.cache.borrow_mut();
+        let w = cache
+            .entry(props)
+            .or_insert_with_key(|props| Arc::new(view(props)));
+
+        w.clone().into()
+    }
+
+    /// Discards every cached widget.
+    pub fn clear(&self) {
+        self.cache.borrow_mut().clear();
+    }
+}
+
+impl<P: fmt::Debug, A: 'static> fmt::Debug for Memo<P, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Memo")
+            .field("view", &(self.view as *const ()))
+            .field("cache", &self.cache)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::*;
+    use proptest::prelude::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        static CALLS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn view(label: &u32) -> Widget<'static, ()> {
+        CALLS.with(|c| c.set(c.get() + 1));
+
+        Widget::from(Button {
+            label: label.to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn calls() -> usize {
+        CALLS.with(Cell::take)
+    }
+
+    proptest! {
+        #[test]
+        fn view_is_called_once_for_equal_props(label: u32) {
+            calls();
+            let memo = Memo::new(view);
+
+            let a = memo.view(label);
+            let b = memo.view(label);
+
+            assert_eq!(calls(), 1);
+            assert!(a.ptr_eq(&b));
+            assert_eq!(a, view(&label));
+        }
+
+        #[test]
+        fn view_is_called_again_when_props_change(x: u32, y: u32) {
+            prop_assume!(x != y);
+
+            calls();
+            let memo = Memo::new(view);
+
+            let a = memo.view(x);
+            let b = memo.view(y);
+            let c = memo.view(y);
+
+            assert_eq!(calls(), 2);
+            assert!(!a.ptr_eq(&b));
+            assert!(b.ptr_eq(&c));
+        }
+
+        #[test]
+        fn view_is_called_once_per_item_when_shared(labels: Vec<u32>) {
+            calls();
+            let memo = Memo::new(view);
+
+            let a: Vec<_> = labels.iter().map(|&l| memo.view(l)).collect();
+            let b: Vec<_> = labels.iter().map(|&l| memo.view(l)).collect();
+
+            let distinct: std::collections::HashSet<_> = labels.iter().collect();
+            assert_eq!(calls(), distinct.len());
+            assert!(a.iter().zip(&b).all(|(x, y)| x.ptr_eq(y)));
+        }
+
+        #[test]
+        fn clear_discards_the_cached_widget(label: u32) {
+            calls();
+            let memo = Memo::new(view);
+
+            let a = memo.view(label);
+            memo.clear();
+            let b = memo.view(label);
+
+            assert_eq!(calls(), 2);
+            assert!(!a.ptr_eq(&b));
+            assert_eq!(a, b);
+        }
+
+        #[test]
+        fn unchanged_props_produce_no_changes(label: u32) {
+            let memo = Memo::new(view);
+
+            let x = Widget::from(Column { children: Box::new([memo.view(label)]), ..Default::default() });
+            let y = Widget::from(Column { children: Box::new([memo.view(label)]), ..Default::default() });
+
+            assert_eq!(crate::diff(&x, &y), vec![]);
+        }
+    }
+}
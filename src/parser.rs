@@ -0,0 +1,961 @@
+//! Parsing of widget trees from text at runtime.
+//!
+//! [`parse`] accepts the same syntax as the [`widget!`](macro@crate::widget) macro, except that
+//! property values are restricted to string literals, unsigned integers, `true`, `false` and
+//! names, the latter being bound to handlers and components through a [`Registry`].
+//! Line comments starting with `//` are ignored.
+//!
+//! ## Example
+//! ```rust
+//! use oxidizer::{event::Clicked, parser::{parse, Registry}, widget::Button, widget, Widget};
+//!
+//! #[derive(Debug, Eq, PartialEq)]
+//! enum Action {
+//!     Add,
+//! }
+//!
+//! let add: fn(&Button<Action>, &Clicked) -> Action = |_, _| Action::Add;
+//! let registry = Registry::new().button("add", add);
+//!
+//! let ui: Widget<Action> = parse(
+//!     r#"
+//!     Column [
+//!         Row [
+//!             Entry
+//!             Button { label: "Add", handler: add }
+//!         ]
+//!     ]
+//!     "#,
+//!     &registry,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(ui, widget!(Column [ Row [ Entry Button { label: "Add", handler: add } ] ]));
+//! ```
+
+use crate::event::{Clicked, Collapsed, Dismissed, Entered, Expanded, Scrolled, Selected, Toggled};
+use crate::{widget::*, AnyComponent, Component, Handler};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::iter::Peekable;
+use std::str::Chars;
+
+type ScrollHandler<A> = fn(&Scroll<A>, &Scrolled) -> A;
+type ModalHandler<A> = fn(&Modal<A>, &Dismissed) -> A;
+
+/// Named handlers and components that widgets may refer to.
+#[derive(derivative::Derivative)]
+#[derivative(Debug(bound = ""), Default(bound = ""))]
+pub struct Registry<A> {
+    buttons: HashMap<String, Handler<Button<A>, Clicked, A>>,
+    entries: HashMap<String, Handler<Entry<A>, Entered, A>>,
+    checkboxes: HashMap<String, Handler<Checkbox<A>, Toggled, A>>,
+    scrolls: HashMap<String, ScrollHandler<A>>,
+    expands: HashMap<String, Handler<TreeView<A>, Expanded, A>>,
+    collapses: HashMap<String, Handler<TreeView<A>, Collapsed, A>>,
+    selects: HashMap<String, Handler<TreeView<A>, Selected, A>>,
+    modals: HashMap<String, ModalHandler<A>>,
+    components: HashMap<String, AnyComponent<A>>,
+}
+
+impl<A> Registry<A> {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Registers a [`Button`] handler under `name`.
+    pub fn button(
+        mut self,
+        name: impl Into<String>,
+        handler: impl Into<Handler<Button<A>, Clicked, A>>,
+    ) -> Self {
+        self.buttons.insert(name.into(), handler.into());
+        self
+    }
+
+    /// Registers an [`Entry`] handler under `name`.
+    pub fn entry(
+        mut self,
+        name: impl Into<String>,
+        handler: impl Into<Handler<Entry<A>, Entered, A>>,
+    ) -> Self {
+        self.entries.insert(name.into(), handler.into());
+        self
+    }
+
+    /// Registers a [`Checkbox`] handler under `name`.
+    pub fn checkbox(
+        mut self,
+        name: impl Into<String>,
+        handler: impl Into<Handler<Checkbox<A>, Toggled, A>>,
+    ) -> Self {
+        self.checkboxes.insert(name.into(), handler.into());
+        self
+    }
+
+    /// Registers a [`Scroll`] handler under `name`.
+    pub fn scroll(
+        mut self,
+        name: impl Into<String>,
+        handler: fn(&Scroll<A>, &Scrolled) -> A,
+    ) -> Self {
+        self.scrolls.insert(name.into(), handler);
+        self
+    }
+
+    /// Registers a [`TreeView`] handler of expanded nodes under `name`.
+    pub fn tree_view_expand(
+        mut self,
+        name: impl Into<String>,
+        handler: impl Into<Handler<TreeView<A>, Expanded, A>>,
+    ) -> Self {
+        self.expands.insert(name.into(), handler.into());
+        self
+    }
+
+    /// Registers a [`TreeView`] handler of collapsed nodes under `name`.
+    pub fn tree_view_collapse(
+        mut self,
+        name: impl Into<String>,
+        handler: impl Into<Handler<TreeView<A>, Collapsed, A>>,
+    ) -> Self {
+        self.collapses.insert(name.into(), handler.into());
+        self
+    }
+
+    /// Registers a [`TreeView`] handler of selected nodes under `name`.
+    pub fn tree_view_select(
+        mut self,
+        name: impl Into<String>,
+        handler: impl Into<Handler<TreeView<A>, Selected, A>>,
+    ) -> Self {
+        self.selects.insert(name.into(), handler.into());
+        self
+    }
+
+    /// Registers a [`Modal`] handler under `name`.
+    pub fn modal(
+        mut self,
+        name: impl Into<String>,
+        handler: fn(&Modal<A>, &Dismissed) -> A,
+    ) -> Self {
+        self.modals.insert(name.into(), handler);
+        self
+    }
+
+    /// Registers a [`Component`] under `name`, to be mounted by [`Stateful`] widgets.
+    pub fn component(mut self, name: impl Into<String>, component: impl Component<A>) -> Self {
+        self.components.insert(name.into(), component.into());
+        self
+    }
+}
+
+/// The widgets [`parse`] knows, each with whether it takes children.
+const KINDS: &[(&str, bool)] = &[
+    ("Row", true),
+    ("Column", true),
+    ("Scroll", true),
+    ("Stack", true),
+    ("Modal", true),
+    ("Button", false),
+    ("Entry", false),
+    ("Checkbox", false),
+    ("TreeView", false),
+    ("Text", false),
+    ("Stateful", false),
+];
+
+/// The error returned when parsing malformed text.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ParseError {
+    /// The line where the error occurred, starting from 1.
+    pub line: usize,
+    /// The column where the error occurred, starting from 1.
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a single widget tree out of `text`, binding names through `registry`.
+pub fn parse<A: 'static>(
+    text: &str,
+    registry: &Registry<A>,
+) -> Result<Widget<'static, A>, ParseError> {
+    let mut parser = Parser {
+        lexer: Lexer::new(text),
+        registry,
+    };
+
+    let widget = parser.widget()?;
+
+    match parser.lexer.next()? {
+        (_, Token::End) => Ok(widget),
+        (at, t) => Err(at.error(format!("expected end of input, found {}", t))),
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl Position {
+    fn error(self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Token {
+    Name(String),
+    Str(String),
+    Int(usize),
+    Open(char),
+    Close(char),
+    Colon,
+    Comma,
+    End,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Name(n) => write!(f, "`{}`", n),
+            Token::Str(s) => write!(f, "{:?}", s),
+            Token::Int(n) => write!(f, "`{}`", n),
+            Token::Open(c) | Token::Close(c) => write!(f, "`{}`", c),
+            Token::Colon => write!(f, "`:`"),
+            Token::Comma => write!(f, "`,`"),
+            Token::End => write!(f, "end of input"),
+        }
+    }
+}
+
+struct Lexer<'t> {
+    chars: Peekable<Chars<'t>>,
+    position: Position,
+    peeked: Option<(Position, Token)>,
+}
+
+impl<'t> Lexer<'t> {
+    fn new(text: &'t str) -> Self {
+        Lexer {
+            chars: text.chars().peekable(),
+            position: Position { line: 1, column: 1 },
+            peeked: None,
+        }
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+
+        if c == '\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else {
+            self.position.column += 1;
+        }
+
+        Some(c)
+    }
+
+    fn peek(&mut self) -> Result<&(Position, Token), ParseError> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.lex()?);
+        }
+
+        Ok(self.peeked.as_ref().expect("token was just peeked"))
+    }
+
+    fn next(&mut self) -> Result<(Position, Token), ParseError> {
+        match self.peeked.take() {
+            Some(token) => Ok(token),
+            None => self.lex(),
+        }
+    }
+
+    fn lex(&mut self) -> Result<(Position, Token), ParseError> {
+        loop {
+            match self.chars.peek() {
+                Some(c) if c.is_whitespace() => {
+                    self.bump();
+                }
+
+                Some('/') => {
+                    let at = self.position;
+                    self.bump();
+
+                    if self.bump() != Some('/') {
+                        return Err(at.error("unexpected character `/`"));
+                    }
+
+                    while !matches!(self.bump(), Some('\n') | None) {}
+                }
+
+                _ => break,
+            }
+        }
+
+        let at = self.position;
+
+        let token = match self.bump() {
+            None => Token::End,
+            Some(c @ '{') | Some(c @ '[') => Token::Open(c),
+            Some(c @ '}') | Some(c @ ']') => Token::Close(c),
+            Some(':') => Token::Colon,
+            Some(',') => Token::Comma,
+            Some('"') => Token::Str(self.string(at)?),
+            Some(c) if c.is_ascii_digit() => {
+                let mut digits = c.to_string();
+
+                while let Some(&c) = self.chars.peek() {
+                    if c.is_ascii_digit() {
+                        digits.push(c);
+                        self.bump();
+                    } else {
+                        break;
+                    }
+                }
+
+                Token::Int(
+                    digits
+                        .parse()
+                        .map_err(|_| at.error("integer out of range"))?,
+                )
+            }
+
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                let mut name = c.to_string();
+
+                while let Some(&c) = self.chars.peek() {
+                    if c.is_alphanumeric() || c == '_' {
+                        name.push(c);
+                        self.bump();
+                    } else {
+                        break;
+                    }
+                }
+
+                Token::Name(name)
+            }
+
+            Some(c) => return Err(at.error(format!("unexpected character `{}`", c))),
+        };
+
+        Ok((at, token))
+    }
+
+    fn string(&mut self, start: Position) -> Result<String, ParseError> {
+        let mut value = String::new();
+
+        loop {
+            let at = self.position;
+
+            match self.bump() {
+                None => return Err(start.error("unterminated string")),
+                Some('"') => return Ok(value),
+                Some('\\') => value.push(match self.bump() {
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('0') => '\0',
+                    Some(c @ '\\') | Some(c @ '"') | Some(c @ '\'') => c,
+                    Some('u') => self.unicode(at)?,
+                    _ => return Err(at.error("invalid escape sequence")),
+                }),
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    fn unicode(&mut self, at: Position) -> Result<char, ParseError> {
+        if self.bump() != Some('{') {
+            return Err(at.error("invalid unicode escape"));
+        }
+
+        let mut code = String::new();
+
+        loop {
+            match self.bump() {
+                Some('}') => break,
+                Some(c) if c.is_ascii_hexdigit() => code.push(c),
+                _ => return Err(at.error("invalid unicode escape")),
+            }
+        }
+
+        u32::from_str_radix(&code, 16)
+            .ok()
+            .and_then(std::char::from_u32)
+            .ok_or_else(|| at.error("invalid unicode escape"))
+    }
+}
+
+/// The value of a property.
+enum Value {
+    Str(String),
+    Int(usize),
+    Bool(bool),
+    Name(String),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Str(_) => write!(f, "a string"),
+            Value::Int(_) => write!(f, "an integer"),
+            Value::Bool(_) => write!(f, "a boolean"),
+            Value::Name(_) => write!(f, "a name"),
+        }
+    }
+}
+
+struct Parser<'t, 'r, A> {
+    lexer: Lexer<'t>,
+    registry: &'r Registry<A>,
+}
+
+impl<'t, 'r, A: 'static> Parser<'t, 'r, A> {
+    fn expect(&mut self, expected: Token) -> Result<Position, ParseError> {
+        match self.lexer.next()? {
+            (at, t) if t == expected => Ok(at),
+            (at, t) => Err(at.error(format!("expected {}, found {}", expected, t))),
+        }
+    }
+
+    fn widget(&mut self) -> Result<Widget<'static, A>, ParseError> {
+        let (at, kind) = match self.lexer.next()? {
+            (at, Token::Name(kind)) => (at, kind),
+            (at, t) => return Err(at.error(format!("expected a widget, found {}", t))),
+        };
+
+        let parent = match KINDS.iter().find(|(k, _)| *k == kind) {
+            Some(&(_, parent)) => parent,
+            None => return Err(at.error(format!("unknown widget `{}`", kind))),
+        };
+
+        let properties = self.properties()?;
+
+        let children = match self.lexer.peek()? {
+            (at, Token::Open('[')) if !parent => {
+                return Err(at.error(format!("{} can't have children", kind)))
+            }
+
+            (_, Token::Open('[')) => self.children()?,
+            _ => Vec::new(),
+        };
+
+        let mut widget = match kind.as_str() {
+            "Row" => Widget::from(Row {
+                children: children.into(),
+                ..Default::default()
+            }),
+
+            "Column" => Widget::from(Column {
+                children: children.into(),
+                ..Default::default()
+            }),
+
+            "Scroll" => Widget::from(Scroll {
+                children: children.into(),
+                ..Default::default()
+            }),
+
+            "Stack" => Widget::from(Stack {
+                children: children.into(),
+                ..Default::default()
+            }),
+
+            "Modal" => Widget::from(Modal {
+                children: children.into(),
+                ..Default::default()
+            }),
+
+            "Button" => Widget::from(Button::default()),
+            "Entry" => Widget::from(Entry::default()),
+            "Checkbox" => Widget::from(Checkbox::default()),
+            "TreeView" => Widget::from(TreeView::default()),
+            "Text" => Widget::from(Text::default()),
+            "Stateful" => Widget::from(Stateful::default()),
+            _ => unreachable!("every widget in KINDS is built above, but not `{}`", kind),
+        };
+
+        for (at, name, value) in properties {
+            self.assign(&mut widget, &name, value)
+                .map_err(|message| at.error(message))?;
+        }
+
+        Ok(widget)
+    }
+
+    fn properties(&mut self) -> Result<Vec<(Position, String, Value)>, ParseError> {
+        let mut properties: Vec<(Position, String, Value)> = Vec::new();
+
+        if self.lexer.peek()?.1 != Token::Open('{') {
+            return Ok(properties);
+        }
+
+        self.lexer.next()?;
+
+        loop {
+            let (at, name) = match self.lexer.next()? {
+                (_, Token::Close('}')) => return Ok(properties),
+                (at, Token::Name(name)) => (at, name),
+                (at, t) => return Err(at.error(format!("expected a property, found {}", t))),
+            };
+
+            if properties.iter().any(|(_, n, _)| *n == name) {
+                return Err(at.error(format!("duplicate property `{}`", name)));
+            }
+
+            // Like struct field init shorthand, `{ handler }` stands for `{ handler: handler }`.
+            let value = if self.lexer.peek()?.1 == Token::Colon {
+                self.lexer.next()?;
+
+                match self.lexer.next()? {
+                    (_, Token::Str(s)) => Value::Str(s),
+                    (_, Token::Int(n)) => Value::Int(n),
+                    (_, Token::Name(n)) if n == "true" => Value::Bool(true),
+                    (_, Token::Name(n)) if n == "false" => Value::Bool(false),
+                    (_, Token::Name(n)) => Value::Name(n),
+                    (at, t) => return Err(at.error(format!("expected a value, found {}", t))),
+                }
+            } else {
+                Value::Name(name.clone())
+            };
+
+            properties.push((at, name, value));
+
+            match self.lexer.next()? {
+                (_, Token::Comma) => {}
+                (_, Token::Close('}')) => return Ok(properties),
+                (at, t) => return Err(at.error(format!("expected `,` or `}}`, found {}", t))),
+            }
+        }
+    }
+
+    fn children(&mut self) -> Result<Vec<Widget<'static, A>>, ParseError> {
+        self.expect(Token::Open('['))?;
+
+        let mut children = Vec::new();
+
+        loop {
+            match self.lexer.peek()? {
+                (_, Token::Close(']')) => {
+                    self.lexer.next()?;
+                    return Ok(children);
+                }
+
+                (at, Token::End) => return Err(at.error("expected `]`, found end of input")),
+                _ => children.push(self.widget()?),
+            }
+        }
+    }
+
+    fn assign(
+        &self,
+        widget: &mut Widget<'static, A>,
+        name: &str,
+        value: Value,
+    ) -> Result<(), String> {
+        let registry = self.registry;
+
+        let string = |value: Value| match value {
+            Value::Str(s) => Ok(s),
+            v => Err(format!("expected a string, found {}", v)),
+        };
+
+        let integer = |value: Value| match value {
+            Value::Int(n) => Ok(n),
+            v => Err(format!("expected an integer, found {}", v)),
+        };
+
+        let boolean = |value: Value| match value {
+            Value::Bool(b) => Ok(b),
+            v => Err(format!("expected a boolean, found {}", v)),
+        };
+
+        match (widget, name) {
+            (Widget::Button(w), "label") => w.make_owned().label = string(value)?,
+            (Widget::Button(w), "handler") => {
+                w.make_owned().handler =
+                    (*lookup(&registry.buttons, value, "Button handler")?).into();
+            }
+
+            (Widget::Entry(w), "value") => w.make_owned().value = string(value)?,
+            (Widget::Entry(w), "handler") => {
+                w.make_owned().handler =
+                    (*lookup(&registry.entries, value, "Entry handler")?).into();
+            }
+
+            (Widget::Checkbox(w), "label") => w.make_owned().label = string(value)?,
+            (Widget::Checkbox(w), "value") => w.make_owned().value = boolean(value)?,
+
+            (Widget::Checkbox(w), "handler") => {
+                w.make_owned().handler =
+                    (*lookup(&registry.checkboxes, value, "Checkbox handler")?).into();
+            }
+
+            (Widget::Text(w), "content") => w.make_owned().content = string(value)?,
+
+            (Widget::Stateful(w), "key") => w.make_owned().key = string(value)?,
+            (Widget::Stateful(w), "component") => {
+                w.make_owned().component =
+                    lookup(&registry.components, value, "component")?.duplicate();
+            }
+
+            (Widget::Scroll(w), "handler") => {
+                w.make_owned().handler = Some(*lookup(&registry.scrolls, value, "Scroll handler")?);
+            }
+
+            (Widget::TreeView(w), "expand_handler") => {
+                w.make_owned().expand_handler =
+                    (*lookup(&registry.expands, value, "TreeView expand handler")?).into();
+            }
+
+            (Widget::TreeView(w), "collapse_handler") => {
+                w.make_owned().collapse_handler =
+                    (*lookup(&registry.collapses, value, "TreeView collapse handler")?).into();
+            }
+
+            (Widget::TreeView(w), "select_handler") => {
+                w.make_owned().select_handler =
+                    (*lookup(&registry.selects, value, "TreeView select handler")?).into();
+            }
+
+            (Widget::Stack(w), "active") => w.make_owned().active = integer(value)?,
+
+            (Widget::Modal(w), "title") => w.make_owned().title = string(value)?,
+            (Widget::Modal(w), "open") => w.make_owned().open = boolean(value)?,
+            (Widget::Modal(w), "handler") => {
+                w.make_owned().handler = Some(*lookup(&registry.modals, value, "Modal handler")?);
+            }
+
+            (Widget::Row(w), "style") => w.make_owned().style = string(value)?.into(),
+            (Widget::Column(w), "style") => w.make_owned().style = string(value)?.into(),
+            (Widget::Button(w), "style") => w.make_owned().style = string(value)?.into(),
+            (Widget::Entry(w), "style") => w.make_owned().style = string(value)?.into(),
+            (Widget::Checkbox(w), "style") => w.make_owned().style = string(value)?.into(),
+            (Widget::Text(w), "style") => w.make_owned().style = string(value)?.into(),
+            (Widget::Scroll(w), "style") => w.make_owned().style = string(value)?.into(),
+            (Widget::TreeView(w), "style") => w.make_owned().style = string(value)?.into(),
+            (Widget::Stack(w), "style") => w.make_owned().style = string(value)?.into(),
+            (Widget::Modal(w), "style") => w.make_owned().style = string(value)?.into(),
+
+            (Widget::Row(w), "disabled") => w.make_owned().disabled = boolean(value)?,
+            (Widget::Column(w), "disabled") => w.make_owned().disabled = boolean(value)?,
+            (Widget::Scroll(w), "disabled") => w.make_owned().disabled = boolean(value)?,
+            (Widget::Button(w), "disabled") => w.make_owned().disabled = boolean(value)?,
+            (Widget::Entry(w), "disabled") => w.make_owned().disabled = boolean(value)?,
+            (Widget::Entry(w), "readonly") => w.make_owned().readonly = boolean(value)?,
+            (Widget::Checkbox(w), "disabled") => w.make_owned().disabled = boolean(value)?,
+            (Widget::TreeView(w), "disabled") => w.make_owned().disabled = boolean(value)?,
+            (Widget::Stack(w), "disabled") => w.make_owned().disabled = boolean(value)?,
+            (Widget::Modal(w), "disabled") => w.make_owned().disabled = boolean(value)?,
+
+            (w, name) => return Err(format!("unknown property `{}` for {}", name, kind(w))),
+        }
+
+        Ok(())
+    }
+}
+
+fn lookup<'m, T>(map: &'m HashMap<String, T>, value: Value, kind: &str) -> Result<&'m T, String> {
+    match value {
+        Value::Name(n) => map
+            .get(&n)
+            .ok_or_else(|| format!("unknown {} `{}`", kind, n)),
+        v => Err(format!("expected a name, found {}", v)),
+    }
+}
+
+fn kind<A>(widget: &Widget<A>) -> &'static str {
+    match widget {
+        Widget::Row(_) => "Row",
+        Widget::Column(_) => "Column",
+        Widget::Scroll(_) => "Scroll",
+        Widget::VirtualList(_) => "VirtualList",
+        Widget::Table(_) => "Table",
+        Widget::Grid(_) => "Grid",
+        Widget::Stack(_) => "Stack",
+        Widget::Tabs(_) => "Tabs",
+        Widget::Modal(_) => "Modal",
+        Widget::Button(_) => "Button",
+        Widget::Entry(_) => "Entry",
+        Widget::Checkbox(_) => "Checkbox",
+        Widget::TreeView(_) => "TreeView",
+        Widget::Text(_) => "Text",
+        Widget::Stateful(_) => "Stateful",
+        Widget::Shared(w) => kind(w),
+        Widget::Custom(w) => w.name(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{widget, Event};
+    use proptest::prelude::*;
+    use std::convert::TryFrom;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    enum Action {
+        Add,
+        Edit(String),
+        Toggle(bool),
+        Any,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    struct Counter;
+
+    impl TryFrom<Action> for Counter {
+        type Error = Action;
+
+        fn try_from(action: Action) -> Result<Self, Action> {
+            Err(action)
+        }
+    }
+
+    impl Component<Action> for Counter {
+        type State = ();
+        type Message = Counter;
+
+        fn update(&self, _: &mut (), _: Counter) -> Option<Action> {
+            None
+        }
+
+        fn view(&self, _: &()) -> Widget<'static, Action> {
+            widget!(Button)
+        }
+    }
+
+    const ADD: fn(&Button<Action>, &Clicked) -> Action = |_, _| Action::Add;
+    const EDIT: fn(&Entry<Action>, &Entered) -> Action = |_, e| Action::Edit(e.value.clone());
+    const TOGGLE: fn(&Checkbox<Action>, &Toggled) -> Action = |_, e| Action::Toggle(e.value);
+    const ANY: fn(&Widget<Action>, &Event) -> Action = |_, _| Action::Any;
+    const SCROLL: fn(&Scroll<Action>, &Scrolled) -> Action = |_, _| Action::Any;
+    const EXPAND: fn(&TreeView<Action>, &Expanded) -> Action = |_, _| Action::Any;
+    const COLLAPSE: fn(&TreeView<Action>, &Collapsed) -> Action = |_, _| Action::Any;
+    const SELECT: fn(&TreeView<Action>, &Selected) -> Action = |_, _| Action::Any;
+    const DISMISS: fn(&Modal<Action>, &Dismissed) -> Action = |_, _| Action::Any;
+
+    fn registry() -> Registry<Action> {
+        let (add, edit, toggle, any) = (ADD, EDIT, TOGGLE, ANY);
+
+        Registry::new()
+            .button("add", add)
+            .button("handler", any)
+            .entry("edit", edit)
+            .checkbox("toggle", toggle)
+            .scroll("scroll", SCROLL)
+            .tree_view_expand("expand", EXPAND)
+            .tree_view_collapse("collapse", COLLAPSE)
+            .tree_view_select("select", SELECT)
+            .modal("dismiss", DISMISS)
+            .component("counter", Counter)
+    }
+
+    fn error(text: &str) -> (usize, usize, String) {
+        let e = parse(text, &registry()).unwrap_err();
+        (e.line, e.column, e.message)
+    }
+
+    #[test]
+    fn parses_the_widget_macro_syntax() {
+        let (add, edit, toggle, handler) = (ADD, EDIT, TOGGLE, ANY);
+        let (scroll, dismiss) = (SCROLL, DISMISS);
+        let (expand, collapse, select) = (EXPAND, COLLAPSE, SELECT);
+
+        let text = r#"
+            // The main screen.
+            Column [
+                Row { style: "toolbar dense" } [
+                    Entry { value: "milk", handler: edit, readonly: true }
+                    Button { label: "Add \"Todo\"\n", handler: add, disabled: false, }
+                ]
+                Checkbox { label: "buy milk", value: true, handler: toggle }
+                Button { handler, style: "primary" }
+                Stateful { key: "a", component: counter }
+                Row []
+                Column { disabled: true }
+                Scroll { style: "list", handler: scroll } [ Entry ]
+                TreeView {
+                    style: "files",
+                    disabled: true,
+                    expand_handler: expand,
+                    collapse_handler: collapse,
+                    select_handler: select,
+                }
+                Stack { active: 1, style: "pages", disabled: false } [ Text Entry ]
+                Modal { title: "Delete?", open: true, style: "danger", handler: dismiss } [
+                    Button { handler: add }
+                ]
+            ]
+        "#;
+
+        assert_eq!(
+            parse(text, &registry()),
+            Ok(widget!(Column [
+                Row { style: "toolbar dense" } [
+                    Entry { value: "milk", handler: edit, readonly: true }
+                    Button { label: "Add \"Todo\"\n", handler: add }
+                ]
+                Checkbox { label: "buy milk", value: true, handler: toggle }
+                Button { handler, style: "primary" }
+                Stateful { key: "a", component: Counter }
+                Row []
+                Column { disabled: true }
+                Scroll { style: "list", handler: scroll } [ Entry ]
+                TreeView {
+                    style: "files",
+                    disabled: true,
+                    expand_handler: expand,
+                    collapse_handler: collapse,
+                    select_handler: select
+                }
+                Stack { active: 1usize, style: "pages" } [ Text Entry ]
+                Modal { title: "Delete?", open: true, style: "danger", handler: dismiss } [
+                    Button { handler: add }
+                ]
+            ]))
+        );
+    }
+
+    #[test]
+    fn errors_report_line_and_column() {
+        let cases = vec![
+            ("", (1, 1, "expected a widget, found end of input")),
+            ("Row Row", (1, 5, "expected end of input, found `Row`")),
+            ("Column [\n  Slider\n]", (2, 3, "unknown widget `Slider`")),
+            (
+                "Column [\n  Entry",
+                (2, 8, "expected `]`, found end of input"),
+            ),
+            ("Slider [ Entry ]", (1, 1, "unknown widget `Slider`")),
+            ("Button [ Entry ]", (1, 8, "Button can't have children")),
+            ("TreeView [ Text ]", (1, 10, "TreeView can't have children")),
+            (
+                "Button { label: 1 }",
+                (1, 10, "expected a string, found an integer"),
+            ),
+            ("Button { label: -1 }", (1, 17, "unexpected character `-`")),
+            (
+                "Stack { active: 18446744073709551616 }",
+                (1, 17, "integer out of range"),
+            ),
+            (
+                "Modal { open: 1 }",
+                (1, 9, "expected a boolean, found an integer"),
+            ),
+            (
+                "Stack { active: \"1\" }",
+                (1, 9, "expected an integer, found a string"),
+            ),
+            ("Button { label: \"x }", (1, 17, "unterminated string")),
+            (
+                "Button { label: \"\\q\" }",
+                (1, 18, "invalid escape sequence"),
+            ),
+            (
+                "Button { label: add }",
+                (1, 10, "expected a string, found a name"),
+            ),
+            (
+                "Button { label: \"\", label: \"\" }",
+                (1, 21, "duplicate property `label`"),
+            ),
+            (
+                "Button { value: \"\" }",
+                (1, 10, "unknown property `value` for Button"),
+            ),
+            (
+                "Button { handler: edit }",
+                (1, 10, "unknown Button handler `edit`"),
+            ),
+            (
+                "Scroll { handler: add }",
+                (1, 10, "unknown Scroll handler `add`"),
+            ),
+            (
+                "TreeView { select_handler: expand }",
+                (1, 12, "unknown TreeView select handler `expand`"),
+            ),
+            (
+                "Modal { handler: scroll }",
+                (1, 9, "unknown Modal handler `scroll`"),
+            ),
+            (
+                "Entry { handler: \"edit\" }",
+                (1, 9, "expected a name, found a string"),
+            ),
+            (
+                "Checkbox { value: \"true\" }",
+                (1, 12, "expected a boolean, found a string"),
+            ),
+            (
+                "Stateful { component: timer }",
+                (1, 12, "unknown component `timer`"),
+            ),
+            ("Row { : }", (1, 7, "expected a property, found `:`")),
+            ("Row { a b }", (1, 9, "expected `,` or `}`, found `b`")),
+            ("Row { a: , }", (1, 10, "expected a value, found `,`")),
+            ("Row / comment", (1, 5, "unexpected character `/`")),
+        ];
+
+        for (text, (line, column, message)) in cases {
+            assert_eq!(error(text), (line, column, message.to_string()), "{}", text);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn strings_round_trip_through_debug(label: String) {
+            let text = format!("Button {{ label: {:?} }}", label);
+            assert_eq!(parse(&text, &registry()), Ok(widget!(Button { label })));
+        }
+
+        #[test]
+        fn integers_round_trip_through_display(active: usize) {
+            let text = format!("Stack {{ active: {} }} [ Text Entry ]", active);
+            assert_eq!(parse(&text, &registry()), Ok(widget!(Stack { active } [ Text Entry ])));
+        }
+
+        #[test]
+        fn modals_round_trip_through_debug(title: String, open: bool) {
+            let text = format!("Modal {{ title: {:?}, open: {} }} [ Text ]", title, open);
+            assert_eq!(parse(&text, &registry()), Ok(widget!(Modal { title, open } [ Text ])));
+        }
+
+        #[test]
+        fn dispatch_reaches_registered_handlers(value: String, checked: bool) {
+            let tree = parse(
+                "Row [ Entry { handler: edit } Checkbox { handler: toggle } Button { handler: add } ]",
+                &registry(),
+            )
+            .unwrap();
+
+            let entered = Entered { value: value.clone() };
+            assert_eq!(tree.dispatch(vec![0usize], &entered.into()), Some(Action::Edit(value)));
+
+            let toggled = Toggled { value: checked };
+            assert_eq!(tree.dispatch(vec![1usize], &toggled.into()), Some(Action::Toggle(checked)));
+
+            let clicked = Clicked::default();
+            assert_eq!(tree.dispatch(vec![2usize], &clicked.into()), Some(Action::Add));
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt::{self, Display, Formatter};
+
 pub trait TreePath {
     type Segment;
     type Segments: IntoIterator<Item = Self::Segment>;
@@ -13,6 +15,20 @@ impl<I: IntoIterator> TreePath for I {
     }
 }
 
+/// Displays a path as `/`-separated indices, or `.` if empty.
+pub(crate) struct DisplayPath<'a>(pub(crate) &'a [usize]);
+
+impl<'a> Display for DisplayPath<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            write!(f, ".")
+        } else {
+            let segments: Vec<_> = self.0.iter().map(ToString::to_string).collect();
+            write!(f, "{}", segments.join("/"))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,4 +40,10 @@ mod tests {
             assert_eq!((&path).segments(), &path);
         }
     }
+
+    #[test]
+    fn display() {
+        assert_eq!(DisplayPath(&[]).to_string(), ".");
+        assert_eq!(DisplayPath(&[0, 2, 1]).to_string(), "0/2/1");
+    }
 }
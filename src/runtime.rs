@@ -0,0 +1,1014 @@
+use crate::subscription::Running;
+use crate::{event::Event, widget::*, AnyComponent, Command, Executor, Set, Subscription};
+use crate::{ThreadExecutor, TreePath};
+use std::any::{Any, TypeId};
+use std::collections::{hash_map::Entry::*, HashMap};
+use std::ops::Range;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread::ThreadId;
+
+/// An application driven by a [`Runtime`].
+pub trait Application {
+    /// The actions produced by the widget tree.
+    type Action: 'static + Send;
+
+    /// Updates the state of the application in response to an action.
+    ///
+    /// The [`Command`] returned is driven by the [`Runtime`] and its actions fed back into
+    /// the application as they become available.
+    fn update(&mut self, action: Self::Action) -> Command<Self::Action>;
+
+    /// Builds the widget tree out of the state of the application.
+    fn view(&self) -> Widget<'_, Self::Action>;
+
+    /// Declares the sources of actions the application is subscribed to given its state.
+    ///
+    /// The [`Runtime`] reconciles the running subscriptions after every update.
+    fn subscriptions(&self) -> Subscription<Self::Action> {
+        Subscription::none()
+    }
+
+    /// Extracts the [`Set`] carried by `action`, if any, which the [`Runtime`] applies directly
+    /// to the application instead of calling [`Application::update`].
+    ///
+    /// Widgets bound to the application through [`Lens`](crate::Lens)es, such as
+    /// [`Entry::bound`], produce actions out of [`Set`]s.
+    fn set(action: Self::Action) -> Result<Set<Self>, Self::Action>
+    where
+        Self: Sized,
+    {
+        Err(action)
+    }
+}
+
+/// Applies `action` to `app`, either as a [`Set`] or through [`Application::update`].
+pub(crate) fn apply<App: Application>(app: &mut App, action: App::Action) -> Command<App::Action> {
+    match App::set(action) {
+        Ok(set) => {
+            set.apply(app);
+            Command::none()
+        }
+
+        Err(action) => app.update(action),
+    }
+}
+
+/// An action produced asynchronously.
+pub(crate) enum Outcome<A> {
+    /// An action produced by a task that has completed.
+    Completed(A),
+    /// An action emitted by a subscription.
+    Emitted(A),
+    /// A task that panicked or was dropped before completing.
+    Failed,
+    /// A subscription whose source has returned, identified by the thread it ran on.
+    Finished(ThreadId),
+}
+
+/// Reports the outcome of a task to the [`Runtime`] once it is done, whether it completes,
+/// panics or is dropped by the [`Executor`] before completing.
+struct Task<A> {
+    sender: Option<Sender<Outcome<A>>>,
+}
+
+impl<A> Task<A> {
+    fn complete(mut self, action: A) {
+        if let Some(sender) = self.sender.take() {
+            // The receiver is only gone if the runtime has been dropped.
+            sender.send(Outcome::Completed(action)).ok();
+        }
+    }
+}
+
+impl<A> Drop for Task<A> {
+    fn drop(&mut self) {
+        if let Some(sender) = self.sender.take() {
+            sender.send(Outcome::Failed).ok();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum Slot {
+    Index(usize),
+    Key(String),
+}
+
+/// Identifies the local state of a mounted [`Component`](crate::Component).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Id {
+    parent: Vec<usize>,
+    slot: Slot,
+    component: TypeId,
+}
+
+impl Id {
+    fn new<A>(path: &[usize], widget: &Stateful<A>) -> Option<Self> {
+        let component = widget.component.type_id()?;
+        let (parent, index) = path.split_last().map_or((path, 0), |(&i, p)| (p, i));
+
+        let slot = if widget.key.is_empty() {
+            Slot::Index(index)
+        } else {
+            Slot::Key(widget.key.clone())
+        };
+
+        Some(Id {
+            parent: parent.into(),
+            slot,
+            component,
+        })
+    }
+}
+
+type States = HashMap<Id, Box<dyn Any>>;
+
+/// Drives an [`Application`], keeping the local state of its [`Component`](crate::Component)s.
+///
+/// Backends display the tree built by [`Runtime::view`] and report user interactions back through
+/// [`Runtime::dispatch`].
+/// [`Command`]s are spawned on an [`Executor`], while [`Subscription`]s run on dedicated threads,
+/// the actions they produce are applied by [`Runtime::process`].
+#[derive(derivative::Derivative)]
+#[derivative(Debug(bound = "App: std::fmt::Debug"))]
+pub struct Runtime<App: Application> {
+    app: App,
+    #[derivative(Debug = "ignore")]
+    states: States,
+    #[derivative(Debug = "ignore")]
+    executor: Box<dyn Executor>,
+    #[derivative(Debug = "ignore")]
+    sender: Sender<Outcome<App::Action>>,
+    #[derivative(Debug = "ignore")]
+    receiver: Receiver<Outcome<App::Action>>,
+    pending: usize,
+    #[derivative(Debug = "ignore")]
+    subscriptions: Vec<(String, Running)>,
+}
+
+impl<App: Application> Runtime<App> {
+    /// Drives `app`, spawning commands on a [`ThreadExecutor`].
+    pub fn new(app: App) -> Self {
+        Runtime::with_executor(app, ThreadExecutor)
+    }
+
+    /// Drives `app`, spawning commands on `executor`.
+    pub fn with_executor(app: App, executor: impl Executor + 'static) -> Self {
+        let (sender, receiver) = channel();
+
+        let mut runtime = Runtime {
+            app,
+            states: HashMap::new(),
+            executor: Box::new(executor),
+            sender,
+            receiver,
+            pending: 0,
+            subscriptions: Vec::new(),
+        };
+
+        runtime.subscribe();
+        runtime
+    }
+
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Mutable access to the application.
+    ///
+    /// Subscriptions are only reconciled on the next update.
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    pub fn into_inner(self) -> App {
+        self.app
+    }
+
+    /// The number of tasks spawned whose actions have not been applied yet.
+    ///
+    /// Tasks that panic or are dropped by the executor are no longer pending.
+    pub fn pending(&self) -> usize {
+        self.pending
+    }
+
+    /// The keys of the subscriptions currently running.
+    pub fn subscriptions(&self) -> impl Iterator<Item = &str> {
+        self.subscriptions.iter().map(|(k, _)| k.as_str())
+    }
+
+    fn subscribe(&mut self) {
+        let subscription = self.app.subscriptions();
+        subscription.reconcile(&mut self.subscriptions, &self.sender);
+    }
+
+    /// Spawns the tasks of `command` on the executor.
+    pub fn perform(&mut self, command: Command<App::Action>) {
+        for future in command {
+            let task = Task {
+                sender: Some(self.sender.clone()),
+            };
+
+            self.pending += 1;
+            self.executor
+                .spawn(Box::pin(async move { task.complete(future.await) }));
+        }
+    }
+
+    /// Applies `action` to the application, performing the command returned
+    /// and reconciling subscriptions.
+    pub fn update(&mut self, action: App::Action) {
+        let command = apply(&mut self.app, action);
+        self.perform(command);
+        self.subscribe();
+    }
+
+    /// Applies the action carried by `outcome`, if any, returning whether there was one.
+    fn receive(&mut self, outcome: Outcome<App::Action>) -> bool {
+        match outcome {
+            Outcome::Completed(action) => {
+                self.pending -= 1;
+                self.update(action);
+            }
+
+            Outcome::Emitted(action) => self.update(action),
+
+            Outcome::Failed => {
+                self.pending -= 1;
+                return false;
+            }
+
+            Outcome::Finished(id) => {
+                self.subscriptions.retain(|(_, s)| s.id() != id);
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Applies the actions produced since the last call by tasks and subscriptions.
+    ///
+    /// Returns the number of actions applied.
+    pub fn process(&mut self) -> usize {
+        let mut count = 0;
+
+        while let Ok(outcome) = self.receiver.try_recv() {
+            count += usize::from(self.receive(outcome));
+        }
+
+        count
+    }
+
+    /// Blocks until some action is produced, some task fails or some subscription finishes, then
+    /// applies the actions available like [`Runtime::process`].
+    ///
+    /// Doesn't block if there are neither pending tasks nor running subscriptions.
+    pub fn wait(&mut self) -> usize {
+        if self.pending == 0 && self.subscriptions.is_empty() {
+            return self.process();
+        }
+
+        // Every task and subscription reports back when done, even if it fails, and the runtime
+        // holds a sender itself, so this only returns once something happened.
+        let outcome = self.receiver.recv().expect("the runtime holds a sender");
+        usize::from(self.receive(outcome)) + self.process()
+    }
+
+    /// Builds the widget tree with every component rendered out of its local state.
+    ///
+    /// The local state of components that are no longer part of the tree is dropped.
+    pub fn view(&mut self) -> Widget<'_, App::Action> {
+        let Runtime { app, states, .. } = self;
+        let mut mounted = HashMap::with_capacity(states.len());
+        let tree = app.view();
+        let components = Components::of(&tree);
+        let tree = render(&mut Vec::new(), tree, &components, states, &mut mounted);
+        *states = mounted;
+        tree
+    }
+
+    /// Routes `event` to the widget at `path` and applies the resulting action, if any.
+    ///
+    /// Enclosing components get a chance to handle the action first, from the innermost outwards.
+    pub fn dispatch<'e, S: Into<usize>>(
+        &mut self,
+        path: impl TreePath<Segment = S>,
+        event: impl Into<Event<'e>>,
+    ) {
+        let path: Vec<_> = path.segments().into_iter().map(Into::into).collect();
+        self.dispatch_all(Some((path, event.into())));
+    }
+
+    /// Dispatches every event in `batch` in order, like [`Runtime::dispatch`].
+    ///
+    /// The tree is only built once for the whole batch, so every path refers to the tree as it was
+    /// before the first event.
+    /// Queue events in a [`Batch`](crate::event::Batch) to coalesce repeated changes to the same
+    /// widget within a frame, such that only the last value is delivered.
+    pub fn dispatch_all<'e>(&mut self, batch: impl IntoIterator<Item = (Vec<usize>, Event<'e>)>) {
+        let routed: Vec<_> = {
+            let tree = self.view();
+
+            batch
+                .into_iter()
+                .filter_map(|(path, event)| {
+                    let action = tree.dispatch(path.iter().copied(), &event)?;
+                    Some((action, enclosing(&tree, &path)))
+                })
+                .collect()
+        };
+
+        for (action, components) in routed {
+            self.bubble(action, components);
+        }
+    }
+
+    /// Lets `components` handle `action`, from the innermost outwards, and applies what is left
+    /// of it, if anything.
+    fn bubble(
+        &mut self,
+        mut action: App::Action,
+        components: Vec<(Id, AnyComponent<App::Action>)>,
+    ) {
+        for (id, component) in components.into_iter().rev() {
+            let state = self.states.get_mut(&id).expect("component is mounted");
+
+            match component.update(&mut **state, action) {
+                Ok(None) => return,
+                Ok(Some(a)) | Err(a) => action = a,
+            }
+        }
+
+        self.update(action);
+    }
+
+    /// Dispatches every step of a recorded [`Session`](crate::session::Session) in order.
+    pub fn replay(&mut self, session: &crate::session::Session) {
+        for step in &session.steps {
+            self.dispatch(step.path.iter().copied(), &step.event);
+        }
+    }
+}
+
+/// Whether a widget or any of its descendants is a [`Stateful`] widget, along with the same for
+/// each of its children if so.
+struct Components {
+    any: bool,
+    children: Vec<Components>,
+}
+
+impl Components {
+    fn of<A>(widget: &Widget<A>) -> Self {
+        let children: Vec<_> = match widget.resolve() {
+            Widget::Stateful(_) => {
+                return Components {
+                    any: true,
+                    children: Vec::new(),
+                }
+            }
+            Widget::Custom(_) => Vec::new(),
+            w => w.children().iter().map(Components::of).collect(),
+        };
+
+        match children.iter().any(|c| c.any) {
+            true => Components {
+                any: true,
+                children,
+            },
+            false => Components {
+                any: false,
+                children: Vec::new(),
+            },
+        }
+    }
+}
+
+fn render<'w, A: 'static>(
+    path: &mut Vec<usize>,
+    widget: Widget<'w, A>,
+    components: &Components,
+    old: &mut States,
+    new: &mut States,
+) -> Widget<'w, A> {
+    if !components.any {
+        return widget;
+    }
+
+    let children = &components.children;
+
+    match widget {
+        Widget::Row(w) => {
+            let mut w = w.into_owned();
+            w.children =
+                render_children(path, 0, std::mem::take(&mut w.children), children, old, new);
+            w.into()
+        }
+
+        Widget::Column(w) => {
+            let mut w = w.into_owned();
+            w.children =
+                render_children(path, 0, std::mem::take(&mut w.children), children, old, new);
+            w.into()
+        }
+
+        Widget::Scroll(w) => {
+            let mut w = w.into_owned();
+            w.children =
+                render_children(path, 0, std::mem::take(&mut w.children), children, old, new);
+            w.into()
+        }
+
+        Widget::VirtualList(w) => {
+            let mut w = w.into_owned();
+            let start = w.visible().start;
+            w.rows = render_children(path, start, std::mem::take(&mut w.rows), children, old, new);
+            w.into()
+        }
+
+        Widget::Table(w) => {
+            let mut w = w.into_owned();
+            w.cells = render_children(path, 0, std::mem::take(&mut w.cells), children, old, new);
+            w.into()
+        }
+
+        Widget::Grid(w) => {
+            let mut w = w.into_owned();
+            w.children =
+                render_children(path, 0, std::mem::take(&mut w.children), children, old, new);
+            w.into()
+        }
+
+        Widget::Modal(w) => {
+            let mut w = w.into_owned();
+            w.children =
+                render_children(path, 0, std::mem::take(&mut w.children), children, old, new);
+            w.into()
+        }
+
+        Widget::Stack(w) => {
+            let mut w = w.into_owned();
+            let visible = w.visible();
+            w.children = render_visible(
+                path,
+                visible,
+                std::mem::take(&mut w.children),
+                children,
+                old,
+                new,
+            );
+            w.into()
+        }
+
+        Widget::Tabs(w) => {
+            let mut w = w.into_owned();
+            let visible = w.visible();
+            w.pages = render_visible(
+                path,
+                visible,
+                std::mem::take(&mut w.pages),
+                children,
+                old,
+                new,
+            );
+            w.into()
+        }
+
+        Widget::Stateful(w) => {
+            let mut w = w.into_owned();
+
+            if let Some(id) = Id::new(path, &w) {
+                let state = match new.entry(id) {
+                    Occupied(e) => e.into_mut(),
+                    Vacant(e) => {
+                        let state = old.remove(e.key()).or_else(|| w.component.init());
+                        e.insert(state.expect("component is not empty"))
+                    }
+                };
+
+                let view = w.component.view(&**state).expect("component is not empty");
+
+                let components = Components::of(&view);
+
+                path.push(0);
+                w.children = Box::new([render(path, view, &components, old, new)]);
+                path.pop();
+            }
+
+            w.into()
+        }
+
+        Widget::Shared(w) => render(path, (*w).clone(), components, old, new),
+
+        w => w,
+    }
+}
+
+fn render_children<'w, A: 'static>(
+    path: &mut Vec<usize>,
+    start: usize,
+    children: Box<[Widget<'w, A>]>,
+    components: &[Components],
+    old: &mut States,
+    new: &mut States,
+) -> Box<[Widget<'w, A>]> {
+    let children = children.into_vec().into_iter().zip(components).enumerate();
+
+    children
+        .map(|(i, (w, c))| {
+            path.push(start + i);
+            let w = render(path, w, c, old, new);
+            path.pop();
+            w
+        })
+        .collect()
+}
+
+/// Renders the children within `visible` only, leaving the others as they are.
+fn render_visible<'w, A: 'static>(
+    path: &mut Vec<usize>,
+    visible: Range<usize>,
+    children: Box<[Widget<'w, A>]>,
+    components: &[Components],
+    old: &mut States,
+    new: &mut States,
+) -> Box<[Widget<'w, A>]> {
+    let mut children = children.into_vec();
+    let shown = children.drain(visible.clone()).collect();
+    let shown = render_children(path, visible.start, shown, components, old, new);
+    children.splice(visible.start..visible.start, shown.into_vec());
+    children.into()
+}
+
+fn enclosing<A>(root: &Widget<A>, path: &[usize]) -> Vec<(Id, AnyComponent<A>)> {
+    let mut components = Vec::new();
+    let mut widget = root;
+
+    for depth in 0..=path.len() {
+        match widget.resolve() {
+            Widget::Stateful(w) => {
+                if let Some(id) = Id::new(&path[..depth], w) {
+                    components.push((id, w.component.duplicate()));
+                }
+            }
+
+            Widget::Custom(_) => break,
+            _ => {}
+        }
+
+        if let Some(&i) = path.get(depth) {
+            widget = widget.child(i).expect("path is valid");
+        }
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{event::*, executor::block_on, session::Recorder, widget, BoxFuture, Component};
+    use proptest::prelude::*;
+    use std::convert::TryFrom;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    enum Action {
+        Counter(Increment),
+        Submit(u32),
+        Fetch(u32),
+        Fetched(u32),
+        Tick,
+        Stop,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    struct Increment;
+
+    impl From<Increment> for Action {
+        fn from(message: Increment) -> Self {
+            Action::Counter(message)
+        }
+    }
+
+    impl TryFrom<Action> for Increment {
+        type Error = Action;
+
+        fn try_from(action: Action) -> Result<Self, Self::Error> {
+            match action {
+                Action::Counter(m) => Ok(m),
+                a => Err(a),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    struct Counter {
+        limit: u32,
+    }
+
+    impl Component<Action> for Counter {
+        type State = u32;
+        type Message = Increment;
+
+        fn update(&self, count: &mut u32, _: Increment) -> Option<Action> {
+            *count += 1;
+
+            if *count >= self.limit {
+                Some(Action::Submit(std::mem::take(count)))
+            } else {
+                None
+            }
+        }
+
+        fn view(&self, count: &u32) -> Widget<'static, Action> {
+            let handler: fn(&_, &_) -> _ = |_, _| Increment.into();
+            widget!(Button {
+                label: count.to_string(),
+                handler
+            })
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct App {
+        keys: Vec<String>,
+        limit: u32,
+        submitted: Vec<u32>,
+        fetched: Vec<u32>,
+        ticking: bool,
+        ticks: u32,
+        listening: Option<u32>,
+    }
+
+    impl Application for App {
+        type Action = Action;
+
+        fn update(&mut self, action: Action) -> Command<Action> {
+            match action {
+                Action::Submit(n) => self.submitted.push(n),
+                Action::Fetch(n) => return Command::perform(async move { Action::Fetched(n) }),
+                Action::Fetched(n) => self.fetched.push(n),
+                Action::Tick => self.ticks += 1,
+                Action::Stop => self.ticking = false,
+                a => panic!("unexpected action {:?}", a),
+            }
+
+            Command::none()
+        }
+
+        fn view(&self) -> Widget<'_, Action> {
+            let children = self.keys.iter().map(|key| {
+                widget!(Stateful {
+                    key: key.clone(),
+                    component: Counter { limit: self.limit }
+                })
+            });
+
+            Widget::from(Column {
+                children: children.collect(),
+                ..Default::default()
+            })
+        }
+
+        fn subscriptions(&self) -> Subscription<Action> {
+            let ticks = match self.ticking {
+                true => Subscription::every("tick", Duration::from_millis(1), |_| Action::Tick),
+                false => Subscription::none(),
+            };
+
+            let messages = match self.listening {
+                Some(n) => Subscription::receiver("messages", move || {
+                    let (tx, rx) = std::sync::mpsc::channel();
+                    (0..n).for_each(|_| tx.send(Action::Tick).unwrap());
+                    rx
+                }),
+
+                None => Subscription::none(),
+            };
+
+            Subscription::batch([ticks, messages])
+        }
+    }
+
+    fn labels(runtime: &mut Runtime<App>) -> Vec<String> {
+        let tree = runtime.view();
+
+        tree.children()
+            .iter()
+            .map(|w| match w.get(vec![0usize]) {
+                Some(Widget::Button(b)) => b.label.clone(),
+                w => panic!("expected a button, got {:?}", w),
+            })
+            .collect()
+    }
+
+    struct Inline;
+
+    impl Executor for Inline {
+        fn spawn(&self, future: BoxFuture<()>) {
+            block_on(future)
+        }
+    }
+
+    /// An executor that drops every future without polling it.
+    struct Dropping;
+
+    impl Executor for Dropping {
+        fn spawn(&self, _: BoxFuture<()>) {}
+    }
+
+    fn click(runtime: &mut Runtime<App>, i: usize) {
+        runtime.dispatch(vec![i, 0], Clicked::default());
+    }
+
+    proptest! {
+        #[test]
+        fn local_state_survives_rebuilds(n in 1u32..10) {
+            let mut runtime = Runtime::new(App {
+                keys: vec!["".into(), "".into()],
+                limit: 10,
+                ..Default::default()
+            });
+
+            for _ in 0..n {
+                click(&mut runtime, 1);
+            }
+
+            assert_eq!(labels(&mut runtime), vec!["0".to_string(), n.to_string()]);
+            assert_eq!(runtime.app().submitted, Vec::<u32>::new());
+        }
+
+        #[test]
+        fn actions_bubble_up_to_the_application(limit in 1u32..10) {
+            let mut runtime = Runtime::new(App {
+                keys: vec!["".into()],
+                limit,
+                ..Default::default()
+            });
+
+            for _ in 0..limit {
+                click(&mut runtime, 0);
+            }
+
+            assert_eq!(labels(&mut runtime), vec!["0".to_string()]);
+            assert_eq!(runtime.app().submitted, vec![limit]);
+        }
+
+        #[test]
+        fn local_state_is_dropped_when_unmounted(n in 1u32..10) {
+            let mut runtime = Runtime::new(App {
+                keys: vec!["".into()],
+                limit: 10,
+                ..Default::default()
+            });
+
+            for _ in 0..n {
+                click(&mut runtime, 0);
+            }
+
+            runtime.app_mut().keys.clear();
+            assert_eq!(labels(&mut runtime), Vec::<String>::new());
+
+            runtime.app_mut().keys.push("".into());
+            assert_eq!(labels(&mut runtime), vec!["0".to_string()]);
+        }
+
+        #[test]
+        fn keyed_state_follows_the_component(n in 1u32..10) {
+            let mut runtime = Runtime::new(App {
+                keys: vec!["a".into(), "b".into()],
+                limit: 10,
+                ..Default::default()
+            });
+
+            for _ in 0..n {
+                click(&mut runtime, 0);
+            }
+
+            runtime.app_mut().keys.reverse();
+            assert_eq!(labels(&mut runtime), vec!["0".to_string(), n.to_string()]);
+        }
+
+        #[test]
+        fn unhandled_events_are_ignored(e: Event) {
+            prop_assume!(!matches!(e, Event::Clicked(_)));
+
+            let mut runtime = Runtime::new(App {
+                keys: vec!["".into()],
+                limit: 1,
+                ..Default::default()
+            });
+
+            runtime.dispatch(vec![0usize, 0], e.clone());
+            runtime.dispatch(vec![1usize], e);
+
+            assert_eq!(labels(&mut runtime), vec!["0".to_string()]);
+            assert_eq!(runtime.app().submitted, Vec::<u32>::new());
+        }
+
+        #[test]
+        fn commands_feed_actions_back(ns: Vec<u32>) {
+            let mut runtime = Runtime::new(App::default());
+
+            for &n in &ns {
+                runtime.update(Action::Fetch(n));
+            }
+
+            assert_eq!(runtime.pending(), ns.len());
+
+            while runtime.pending() > 0 {
+                assert!(runtime.wait() > 0);
+            }
+
+            let mut fetched = runtime.app().fetched.clone();
+            fetched.sort_unstable();
+
+            let mut expected = ns;
+            expected.sort_unstable();
+
+            assert_eq!(fetched, expected);
+            assert_eq!(runtime.wait(), 0);
+            assert_eq!(runtime.process(), 0);
+        }
+
+        #[test]
+        fn executor_is_pluggable(ns: Vec<u32>) {
+            let mut runtime = Runtime::with_executor(App::default(), Inline);
+
+            for &n in &ns {
+                runtime.update(Action::Fetch(n));
+            }
+
+            assert_eq!(runtime.pending(), ns.len());
+            assert_eq!(runtime.process(), ns.len());
+            assert_eq!(runtime.pending(), 0);
+            assert_eq!(runtime.app().fetched, ns);
+        }
+
+        #[test]
+        fn dropped_tasks_are_no_longer_pending(ns: Vec<u32>) {
+            let mut runtime = Runtime::with_executor(App::default(), Dropping);
+
+            for &n in &ns {
+                runtime.update(Action::Fetch(n));
+            }
+
+            assert_eq!(runtime.process(), 0);
+            assert_eq!(runtime.pending(), 0);
+            assert_eq!(runtime.app().fetched, Vec::<u32>::new());
+        }
+
+        #[test]
+        fn batched_events_are_dispatched_in_order(n in 1u32..10) {
+            let mut runtime = Runtime::new(App {
+                keys: vec!["".into(), "".into()],
+                limit: 10,
+                ..Default::default()
+            });
+
+            let mut batch = Batch::new();
+            batch.extend((0..n).map(|_| (vec![1usize, 0], Clicked::default())));
+            runtime.dispatch_all(batch);
+
+            assert_eq!(labels(&mut runtime), vec!["0".to_string(), n.to_string()]);
+        }
+
+        #[test]
+        fn sessions_can_be_replayed(n in 1u32..10) {
+            let app = || App {
+                keys: vec!["".into(), "".into()],
+                limit: 10,
+                ..Default::default()
+            };
+
+            let mut recorder = Recorder::new();
+            let mut runtime = Runtime::new(app());
+
+            for _ in 0..n {
+                recorder.record(vec![1usize, 0], Clicked::default());
+                click(&mut runtime, 1);
+            }
+
+            let mut replayed = Runtime::new(app());
+            replayed.replay(recorder.session());
+            assert_eq!(labels(&mut replayed), labels(&mut runtime));
+        }
+
+        #[test]
+        fn subscriptions_feed_actions_back(n in 1u32..5) {
+            let mut runtime = Runtime::new(App {
+                ticking: true,
+                ..Default::default()
+            });
+
+            assert_eq!(runtime.subscriptions().collect::<Vec<_>>(), vec!["tick"]);
+
+            while runtime.app().ticks < n {
+                assert!(runtime.wait() > 0);
+            }
+
+            runtime.update(Action::Stop);
+            assert_eq!(runtime.subscriptions().count(), 0);
+        }
+
+        #[test]
+        fn finished_subscriptions_are_no_longer_running(n in 0u32..5) {
+            let mut runtime = Runtime::new(App {
+                listening: Some(n),
+                ..Default::default()
+            });
+
+            assert_eq!(runtime.subscriptions().collect::<Vec<_>>(), vec!["messages"]);
+
+            while runtime.subscriptions().count() > 0 {
+                runtime.wait();
+            }
+
+            assert_eq!(runtime.app().ticks, n);
+            assert_eq!(runtime.wait(), 0);
+        }
+
+        #[test]
+        fn bound_widgets_set_the_state(name: String, public: bool) {
+            let mut runtime = Runtime::new(Profile::default());
+
+            runtime.dispatch(vec![0usize], Entered { value: name.clone() });
+            runtime.dispatch(vec![1usize], Toggled { value: public });
+            assert_eq!(runtime.app().saved, 0);
+
+            runtime.dispatch(vec![2usize], Clicked::default());
+            assert_eq!(runtime.app(), &Profile { name: name.clone(), public, saved: 1 });
+
+            let ui = runtime.view();
+            assert_eq!(ui[[0usize]], widget!(Entry { value: name, handler: &NAME }));
+            assert_eq!(ui[[1usize]], widget!(Checkbox { value: public, handler: &PUBLIC }));
+        }
+    }
+
+    #[test]
+    fn panicking_tasks_are_no_longer_pending() {
+        let mut runtime = Runtime::new(App::default());
+        runtime.perform(Command::perform(async { panic!("task failed") }));
+        runtime.update(Action::Fetch(1));
+
+        while runtime.pending() > 0 {
+            runtime.wait();
+        }
+
+        assert_eq!(runtime.app().fetched, vec![1]);
+        assert_eq!(runtime.wait(), 0);
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    enum Edit {
+        Set(Set<Profile>),
+        Save,
+    }
+
+    impl From<Set<Profile>> for Edit {
+        fn from(set: Set<Profile>) -> Self {
+            Edit::Set(set)
+        }
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    struct Profile {
+        name: String,
+        public: bool,
+        saved: u32,
+    }
+
+    const NAME: crate::Lens<Profile, String> = crate::lens!(Profile, name);
+    const PUBLIC: crate::Lens<Profile, bool> = crate::lens!(Profile, public);
+
+    impl Application for Profile {
+        type Action = Edit;
+
+        fn update(&mut self, action: Edit) -> Command<Edit> {
+            match action {
+                Edit::Set(_) => unreachable!("applied by the runtime"),
+                Edit::Save => self.saved += 1,
+            }
+
+            Command::none()
+        }
+
+        fn view(&self) -> Widget<'_, Edit> {
+            let save: fn(&Button<Edit>, &Clicked) -> Edit = |_, _| Edit::Save;
+
+            widget!(Column [
+                @{ Entry::bound(&NAME, self) }
+                @{ Checkbox::bound(&PUBLIC, self) }
+                Button { label: "Save", handler: save }
+            ])
+        }
+
+        fn set(action: Edit) -> Result<Set<Self>, Edit> {
+            match action {
+                Edit::Set(set) => Ok(set),
+                a => Err(a),
+            }
+        }
+    }
+}
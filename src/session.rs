@@ -0,0 +1,490 @@
+//! Recording and replaying of event sessions.
+//!
+//! A [`Session`] is a log of the events dispatched into a widget tree, which can be saved in a
+//! stable text format and fed back into a tree built by the same view to reproduce the actions.
+//!
+//! ## Format
+//!
+//! Every line holds a single [`Step`], that is the milliseconds elapsed since the beginning of
+//! the session, the path to the target widget as `/`-separated indices (`.` for the root),
+//! the kind of event and its value, if any.
+//! Blank lines and lines starting with `#` are ignored.
+//!
+//! ```text
+//! # oxidizer session
+//! 0 0/1 entered "hello"
+//! 153 0/2 toggled true
+//! 820 1 clicked
+//! 1204 2 scrolled 0,480
+//! 1530 3 sorted 1,desc
+//! 1712 3 row-selected 4
+//! 2045 4 expanded 0/2
+//! 2290 4 selected 0/2/1
+//! 2512 5 dismissed
+//! ```
+//!
+//! ## Example
+//! ```rust
+//! use oxidizer::{event::Clicked, session::{Recorder, Session}, widget::Button, Widget};
+//!
+//! fn view() -> Widget<'static, u32> {
+//!     let handler: fn(&Button<u32>, &Clicked) -> u32 = |_, _| 42;
+//!
+//!     Button {
+//!         handler: handler.into(),
+//!         ..Default::default()
+//!     }
+//!     .into()
+//! }
+//!
+//! let mut recorder = Recorder::new();
+//! assert_eq!(recorder.dispatch(&view(), Vec::<usize>::new(), Clicked::default()), Some(42));
+//!
+//! let session: Session = recorder.into_session().to_string().parse().unwrap();
+//! assert_eq!(session.replay(view), vec![42]);
+//! ```
+
+use crate::event::{Changed, Collapsed, Dismissed, Event, Expanded, Order, Selected};
+use crate::{path::DisplayPath, TreePath, Widget};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// An event dispatched to the widget at `path`, `at` some time since the beginning of a session.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Step {
+    pub at: Duration,
+    pub path: Vec<usize>,
+    pub event: Event<'static>,
+}
+
+/// A log of events dispatched into a widget tree.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Session {
+    pub steps: Vec<Step>,
+}
+
+impl Session {
+    /// Dispatches every step in order into the tree returned by `view`, which is rebuilt after
+    /// each step, and collects the actions produced.
+    pub fn replay<A: 'static>(&self, mut view: impl FnMut() -> Widget<'static, A>) -> Vec<A> {
+        self.steps
+            .iter()
+            .filter_map(|step| view().dispatch(step.path.iter().copied(), &step.event))
+            .collect()
+    }
+}
+
+/// Records the events dispatched into widget trees.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    start: Instant,
+    session: Session,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Recorder::new()
+    }
+}
+
+impl Recorder {
+    /// Starts recording a new session.
+    pub fn new() -> Self {
+        Recorder {
+            start: Instant::now(),
+            session: Session::default(),
+        }
+    }
+
+    /// Logs `event` targeting `path` with the time elapsed since the recording started.
+    pub fn record<'e, S: Into<usize>>(
+        &mut self,
+        path: impl TreePath<Segment = S>,
+        event: impl Into<Event<'e>>,
+    ) {
+        self.session.steps.push(Step {
+            at: self.start.elapsed(),
+            path: path.segments().into_iter().map(Into::into).collect(),
+            event: into_owned(event.into()),
+        });
+    }
+
+    /// Records `event`, then routes it to the handler of the widget at `path` within `tree`.
+    pub fn dispatch<'e, A, S: Into<usize>>(
+        &mut self,
+        tree: &Widget<A>,
+        path: impl TreePath<Segment = S>,
+        event: impl Into<Event<'e>>,
+    ) -> Option<A> {
+        self.record(path, event);
+        let step = self.session.steps.last().expect("step was just recorded");
+        tree.dispatch(step.path.iter().copied(), &step.event)
+    }
+
+    /// The session recorded so far.
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    /// Stops recording.
+    pub fn into_session(self) -> Session {
+        self.session
+    }
+}
+
+fn into_owned(event: Event) -> Event<'static> {
+    match event {
+        Event::Entered(e) => e.into_owned().into(),
+        Event::Toggled(e) => e.into_owned().into(),
+        Event::Clicked(e) => e.into_owned().into(),
+        Event::Scrolled(e) => e.into_owned().into(),
+        Event::Sorted(e) => e.into_owned().into(),
+        Event::RowSelected(e) => e.into_owned().into(),
+        Event::Expanded(e) => e.into_owned().into(),
+        Event::Collapsed(e) => e.into_owned().into(),
+        Event::Selected(e) => e.into_owned().into(),
+        Event::Dismissed(e) => e.into_owned().into(),
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.at.as_millis(), DisplayPath(&self.path))?;
+
+        match &self.event {
+            Event::Entered(e) => write!(f, " entered {:?}", e.value),
+            Event::Toggled(e) => write!(f, " toggled {}", e.value),
+            Event::Clicked(_) => write!(f, " clicked"),
+            Event::Scrolled(e) => write!(f, " scrolled {},{}", e.value.0, e.value.1),
+            Event::Sorted(e) => match e.value {
+                (column, Order::Ascending) => write!(f, " sorted {},asc", column),
+                (column, Order::Descending) => write!(f, " sorted {},desc", column),
+            },
+            Event::RowSelected(e) => write!(f, " row-selected {}", e.value),
+            Event::Expanded(e) => write!(f, " expanded {}", DisplayPath(&e.path)),
+            Event::Collapsed(e) => write!(f, " collapsed {}", DisplayPath(&e.path)),
+            Event::Selected(e) => write!(f, " selected {}", DisplayPath(&e.path)),
+            Event::Dismissed(_) => write!(f, " dismissed"),
+        }
+    }
+}
+
+impl Display for Session {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# oxidizer session")?;
+
+        for step in &self.steps {
+            writeln!(f, "{}", step)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The error returned when parsing a malformed [`Session`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ParseError {
+    /// The line where the error occurred, starting from 1.
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut fields = line.splitn(4, ' ');
+
+        let at = fields.next().unwrap_or_default();
+        let at = at
+            .parse()
+            .map(Duration::from_millis)
+            .map_err(|_| format!("invalid timestamp `{}`", at))?;
+
+        let path = segments(fields.next().ok_or("missing path")?)?;
+
+        let event = match (fields.next(), fields.next()) {
+            (Some("entered"), Some(v)) => Changed { value: unquote(v)? }.into(),
+            (Some("toggled"), Some("true")) => Changed { value: true }.into(),
+            (Some("toggled"), Some("false")) => Changed { value: false }.into(),
+            (Some("toggled"), v) => return Err(format!("invalid toggle `{}`", v.unwrap_or(""))),
+            (Some("clicked"), None) => Changed { value: () }.into(),
+            (Some("scrolled"), Some(v)) => Changed { value: offset(v)? }.into(),
+            (Some("sorted"), Some(v)) => Changed { value: sort(v)? }.into(),
+            (Some("row-selected"), Some(v)) => Changed { value: row(v)? }.into(),
+            (Some("expanded"), Some(v)) => Expanded { path: segments(v)? }.into(),
+            (Some("collapsed"), Some(v)) => Collapsed { path: segments(v)? }.into(),
+            (Some("selected"), Some(v)) => Selected { path: segments(v)? }.into(),
+            (Some("dismissed"), None) => Dismissed.into(),
+            (
+                Some(
+                    kind @ ("entered" | "scrolled" | "sorted" | "row-selected" | "expanded"
+                    | "collapsed" | "selected"),
+                ),
+                None,
+            ) => return Err(format!("missing value for `{}`", kind)),
+            (Some(kind @ ("clicked" | "dismissed")), Some(_)) => {
+                return Err(format!("unexpected value for `{}`", kind))
+            }
+            (Some(kind), _) => return Err(format!("unknown event `{}`", kind)),
+            (None, _) => return Err("missing event".into()),
+        };
+
+        Ok(Step { at, path, event })
+    }
+}
+
+impl FromStr for Session {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let steps = s
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty() && !l.starts_with('#'))
+            .map(|(i, l)| {
+                l.parse().map_err(|message| ParseError {
+                    line: i + 1,
+                    message,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Session { steps })
+    }
+}
+
+/// Parses a path formatted as by [`DisplayPath`].
+fn segments(s: &str) -> Result<Vec<usize>, String> {
+    match s {
+        "." => Ok(Vec::new()),
+        p => p
+            .split('/')
+            .map(|s| s.parse().map_err(|_| format!("invalid path `{}`", p)))
+            .collect(),
+    }
+}
+
+/// Parses a scroll offset formatted as `x,y`.
+fn offset(s: &str) -> Result<(u32, u32), String> {
+    let invalid = || format!("invalid offset `{}`", s);
+    let (x, y) = s.split_once(',').ok_or_else(invalid)?;
+    Ok((
+        x.parse().map_err(|_| invalid())?,
+        y.parse().map_err(|_| invalid())?,
+    ))
+}
+
+/// Parses the index of a row.
+fn row(s: &str) -> Result<usize, String> {
+    s.parse().map_err(|_| format!("invalid row `{}`", s))
+}
+
+/// Parses a sort order formatted as `column,asc` or `column,desc`.
+fn sort(s: &str) -> Result<(usize, Order), String> {
+    let invalid = || format!("invalid sort `{}`", s);
+    let (column, order) = s.split_once(',').ok_or_else(invalid)?;
+    let order = match order {
+        "asc" => Order::Ascending,
+        "desc" => Order::Descending,
+        _ => return Err(invalid()),
+    };
+
+    Ok((column.parse().map_err(|_| invalid())?, order))
+}
+
+/// Parses a string literal escaped as by [`Debug`](std::fmt::Debug).
+fn unquote(s: &str) -> Result<String, String> {
+    let invalid = || format!("invalid string {}", s);
+
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .filter(|_| s.len() >= 2)
+        .ok_or_else(invalid)?;
+
+    let mut value = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Err(invalid()),
+            '\\' => value.push(match chars.next().ok_or_else(invalid)? {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '0' => '\0',
+                c @ '\\' | c @ '"' | c @ '\'' => c,
+                'u' => {
+                    let code: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                    let code = code.strip_prefix('{').ok_or_else(invalid)?;
+                    let code = u32::from_str_radix(code, 16).map_err(|_| invalid())?;
+                    std::char::from_u32(code).ok_or_else(invalid)?
+                }
+                _ => return Err(invalid()),
+            }),
+            c => value.push(c),
+        }
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, prelude::*};
+
+#[cfg(test)]
+impl Arbitrary for Step {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<u32>(), any::<Vec<usize>>(), any::<Event>())
+            .prop_map(|(at, path, event)| Step {
+                at: Duration::from_millis(at.into()),
+                path,
+                event,
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::*;
+    use crate::widget::*;
+
+    fn view() -> Widget<'static, String> {
+        let entered: fn(&Entry<String>, &Entered) -> String = |_, e| e.value.clone();
+        let toggled: fn(&Checkbox<String>, &Toggled) -> String = |_, e| e.value.to_string();
+
+        Widget::from(Column {
+            children: vec![
+                Entry {
+                    handler: entered.into(),
+                    ..Default::default()
+                }
+                .into(),
+                Checkbox {
+                    handler: toggled.into(),
+                    ..Default::default()
+                }
+                .into(),
+                Button::default().into(),
+            ]
+            .into(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn format() {
+        let session = Session {
+            steps: vec![
+                Step {
+                    at: Duration::from_millis(0),
+                    path: vec![0],
+                    event: Entered {
+                        value: "say \"hi\"\n".into(),
+                    }
+                    .into(),
+                },
+                Step {
+                    at: Duration::from_millis(153),
+                    path: vec![1, 2],
+                    event: Toggled { value: true }.into(),
+                },
+                Step {
+                    at: Duration::from_millis(820),
+                    path: vec![],
+                    event: Clicked::default().into(),
+                },
+            ],
+        };
+
+        let text = "# oxidizer session\n\
+                    0 0 entered \"say \\\"hi\\\"\\n\"\n\
+                    153 1/2 toggled true\n\
+                    820 . clicked\n";
+
+        assert_eq!(session.to_string(), text);
+        assert_eq!(text.parse(), Ok(session));
+    }
+
+    #[test]
+    fn errors_point_to_the_line() {
+        let cases = [
+            ("x 0 clicked", "invalid timestamp `x`"),
+            ("0", "missing path"),
+            ("0 0/x clicked", "invalid path `0/x`"),
+            ("0 0", "missing event"),
+            ("0 0 pressed", "unknown event `pressed`"),
+            ("0 0 entered", "missing value for `entered`"),
+            ("0 0 entered \"x", "invalid string \"x"),
+            ("0 0 toggled maybe", "invalid toggle `maybe`"),
+            ("0 0 scrolled 1", "invalid offset `1`"),
+            ("0 0 scrolled", "missing value for `scrolled`"),
+            ("0 0 sorted 1,up", "invalid sort `1,up`"),
+            ("0 0 row-selected", "missing value for `row-selected`"),
+            ("0 0 row-selected x", "invalid row `x`"),
+            ("0 0 selected", "missing value for `selected`"),
+            ("0 0 selected x", "invalid path `x`"),
+            ("0 0 expanded", "missing value for `expanded`"),
+            ("0 0 collapsed 0/a", "invalid path `0/a`"),
+            ("0 0 clicked now", "unexpected value for `clicked`"),
+            ("0 0 dismissed now", "unexpected value for `dismissed`"),
+        ];
+
+        for (line, message) in cases.iter() {
+            let text = format!("# header\n\n0 . clicked\n{}\n", line);
+
+            assert_eq!(
+                text.parse::<Session>(),
+                Err(ParseError {
+                    line: 4,
+                    message: message.to_string()
+                })
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(steps: Vec<Step>) {
+            let session = Session { steps };
+            assert_eq!(session.to_string().parse(), Ok(session));
+        }
+
+        #[test]
+        fn record_and_replay(s: String, b: bool) {
+            let mut recorder = Recorder::new();
+
+            let actions: Vec<_> = vec![
+                recorder.dispatch(&view(), vec![0usize], Entered { value: s.clone() }),
+                recorder.dispatch(&view(), vec![2usize], Clicked::default()),
+                recorder.dispatch(&view(), vec![1usize], Toggled { value: b }),
+                recorder.dispatch(&view(), vec![3usize], Clicked::default()),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+
+            assert_eq!(actions, vec![s, b.to_string()]);
+            assert_eq!(recorder.session().steps.len(), 4);
+
+            let session: Session = recorder.into_session().to_string().parse().unwrap();
+            assert_eq!(session.replay(view), actions);
+        }
+    }
+}
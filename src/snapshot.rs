@@ -0,0 +1,636 @@
+//! Snapshot testing of widget trees.
+//!
+//! [`Dump`] renders a widget tree in a compact, indented and stable text format, where every
+//! line describes a single widget and its children follow one level of indentation deeper.
+//! Handlers and flags such as `disabled` are only shown when present, style classes follow the
+//! name of the widget and [`Widget::Shared`] is transparent.
+//!
+//! ```text
+//! Column
+//!   Row.toolbar
+//!     Entry("", handler)
+//!     Button("Add Todo", handler)
+//!   Checkbox("buy milk", false)
+//! ```
+//!
+//! [`assert_widget_snapshot!`](crate::assert_widget_snapshot) compares such dumps against files
+//! on disk, which are created or updated instead when the environment variable
+//! `OXIDIZER_UPDATE_SNAPSHOTS` is set.
+
+use crate::{event::Order, path::DisplayPath, style::Style, widget::*, OptionalHandler};
+use std::fmt::{self, Display, Formatter};
+use std::{env, fs, io, path::Path};
+
+/// The environment variable that, when set, updates snapshots instead of comparing against them.
+pub const UPDATE_SNAPSHOTS: &str = "OXIDIZER_UPDATE_SNAPSHOTS";
+
+/// Displays a widget tree in a compact text format.
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::{snapshot::Dump, widget, Widget};
+///
+/// let ui: Widget<()> = widget!(Column [ Row [ Entry ] ]);
+/// assert_eq!(Dump(&ui).to_string(), "Column\n  Row\n    Entry(\"\")\n");
+/// ```
+pub struct Dump<'a, 'w, A>(pub &'a Widget<'w, A>);
+
+impl<'a, 'w, A> Dump<'a, 'w, A> {
+    fn fmt_at(widget: &Widget<A>, depth: usize, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:1$}", "", depth * 2)?;
+
+        match widget.resolve() {
+            Widget::Row(w) => write!(f, "Row{}{}", Classes(&w.style), Locked(w.disabled))?,
+            Widget::Column(w) => write!(f, "Column{}{}", Classes(&w.style), Locked(w.disabled))?,
+            Widget::Scroll(w) => write!(
+                f,
+                "Scroll{}({}, {}{}{})",
+                Classes(&w.style),
+                w.offset.0,
+                w.offset.1,
+                Flag(w.handler.is_some(), "handler"),
+                Flag(w.disabled, "disabled")
+            )?,
+            Widget::VirtualList(w) => write!(
+                f,
+                "VirtualList{}({}, {:?}{}{})",
+                Classes(&w.style),
+                w.len(),
+                w.visible(),
+                Flag(w.handler.is_some(), "handler"),
+                Flag(w.disabled, "disabled")
+            )?,
+            Widget::Table(w) => write!(
+                f,
+                "Table{}({:?}{}{}{}{}{})",
+                Classes(&w.style),
+                w.columns().iter().map(|c| &c.header).collect::<Vec<_>>(),
+                Sort(w.sort),
+                Selected(w.selected),
+                Flag(w.sort_handler.is_some(), "sort handler"),
+                Flag(w.select_handler.is_some(), "select handler"),
+                Flag(w.disabled, "disabled")
+            )?,
+            Widget::Grid(w) => write!(
+                f,
+                "Grid{}({}x{}{})",
+                Classes(&w.style),
+                w.size().0,
+                w.size().1,
+                Flag(w.disabled, "disabled")
+            )?,
+            Widget::Stack(w) => write!(
+                f,
+                "Stack{}({} of {}{})",
+                Classes(&w.style),
+                w.active,
+                w.children.len(),
+                Flag(w.disabled, "disabled")
+            )?,
+            Widget::Tabs(w) => write!(
+                f,
+                "Tabs{}({:?}, active {}{}{})",
+                Classes(&w.style),
+                w.labels(),
+                w.active,
+                Flag(w.handler.is_some(), "handler"),
+                Flag(w.disabled, "disabled")
+            )?,
+            Widget::Modal(w) => write!(
+                f,
+                "Modal{}({:?}{}{}{})",
+                Classes(&w.style),
+                w.title,
+                Flag(w.open, "open"),
+                Flag(w.handler.is_some(), "handler"),
+                Flag(w.disabled, "disabled")
+            )?,
+            Widget::Button(w) => write!(
+                f,
+                "Button{}({:?}{}{})",
+                Classes(&w.style),
+                w.label,
+                Present(&w.handler),
+                Flag(w.disabled, "disabled")
+            )?,
+            Widget::Entry(w) => write!(
+                f,
+                "Entry{}({:?}{}{}{})",
+                Classes(&w.style),
+                w.value,
+                Present(&w.handler),
+                Flag(w.disabled, "disabled"),
+                Flag(w.readonly, "readonly")
+            )?,
+            Widget::Checkbox(w) => write!(
+                f,
+                "Checkbox{}({:?}, {}{}{})",
+                Classes(&w.style),
+                w.label,
+                w.value,
+                Present(&w.handler),
+                Flag(w.disabled, "disabled")
+            )?,
+            Widget::TreeView(w) => write!(
+                f,
+                "TreeView{}({:?}{}{}{}{}{})",
+                Classes(&w.style),
+                Labels(w),
+                SelectedNode(w.selected.as_deref()),
+                Flag(
+                    matches!(w.expand_handler, OptionalHandler::Some(_)),
+                    "expand handler"
+                ),
+                Flag(
+                    matches!(w.collapse_handler, OptionalHandler::Some(_)),
+                    "collapse handler"
+                ),
+                Flag(
+                    matches!(w.select_handler, OptionalHandler::Some(_)),
+                    "select handler"
+                ),
+                Flag(w.disabled, "disabled")
+            )?,
+            Widget::Text(w) => write!(f, "Text{}({:?})", Classes(&w.style), w.content)?,
+            Widget::Stateful(w) => write!(f, "Stateful({:?}, {:?})", w.key, w.component)?,
+            Widget::Custom(w) => write!(f, "{}", w.name())?,
+            Widget::Shared(_) => unreachable!("shared widgets are resolved"),
+        }
+
+        writeln!(f)?;
+
+        for child in widget.children() {
+            Self::fmt_at(child, depth + 1, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, 'w, A> Display for Dump<'a, 'w, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Self::fmt_at(self.0, 0, f)
+    }
+}
+
+struct Classes<'a>(&'a Style);
+
+impl<'a> Display for Classes<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.classes().try_for_each(|c| write!(f, ".{}", c))
+    }
+}
+
+/// The disabled flag of widgets without properties, which other widgets list among them.
+struct Locked(bool);
+
+impl Display for Locked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            true => write!(f, "(disabled)"),
+            false => Ok(()),
+        }
+    }
+}
+
+struct Sort(Option<(usize, Order)>);
+
+impl Display for Sort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some((c, Order::Ascending)) => write!(f, ", sorted {} asc", c),
+            Some((c, Order::Descending)) => write!(f, ", sorted {} desc", c),
+            None => Ok(()),
+        }
+    }
+}
+
+struct Selected(Option<usize>);
+
+impl Display for Selected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(row) => write!(f, ", selected {}", row),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The labels of the visible nodes of a tree, indented by depth.
+struct Labels<'a, A>(&'a TreeView<A>);
+
+impl<'a, A> fmt::Debug for Labels<'a, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let tree = self.0;
+        let labels = tree.visible().into_iter().map(|path| {
+            let node = tree.get(path.iter().copied()).expect("path is visible");
+            format!("{}{}", "  ".repeat(path.len() - 1), node.label)
+        });
+
+        f.debug_list().entries(labels).finish()
+    }
+}
+
+struct SelectedNode<'a>(Option<&'a [usize]>);
+
+impl<'a> Display for SelectedNode<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(path) => write!(f, ", selected {}", DisplayPath(path)),
+            None => Ok(()),
+        }
+    }
+}
+
+struct Flag(bool, &'static str);
+
+impl Display for Flag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            true => write!(f, ", {}", self.1),
+            false => Ok(()),
+        }
+    }
+}
+
+struct Present<'a, W, E, A>(&'a OptionalHandler<W, E, A>)
+where
+    for<'e> E: crate::Kind<crate::Event<'e>>,
+    for<'w> W: crate::Kind<Widget<'w, A>>;
+
+impl<'a, W, E, A> Display for Present<'a, W, E, A>
+where
+    for<'e> E: crate::Kind<crate::Event<'e>>,
+    for<'w> W: crate::Kind<Widget<'w, A>>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            OptionalHandler::Some(_) => write!(f, ", handler"),
+            OptionalHandler::None => Ok(()),
+        }
+    }
+}
+
+/// Compares the dump of `widget` against the snapshot stored at `path`.
+///
+/// The snapshot is written instead if the environment variable [`UPDATE_SNAPSHOTS`] is set.
+/// Prefer [`assert_widget_snapshot!`](crate::assert_widget_snapshot), which resolves `path`
+/// relative to the crate being tested.
+///
+/// # Panics
+///
+/// If the snapshot is missing or doesn't match.
+pub fn assert_snapshot<A>(path: impl AsRef<Path>, widget: &Widget<A>) {
+    let update = env::var_os(UPDATE_SNAPSHOTS).map_or(false, |v| !v.is_empty());
+
+    if let Err(message) = check(path.as_ref(), &Dump(widget).to_string(), update) {
+        panic!("{}", message);
+    }
+}
+
+fn check(path: &Path, actual: &str, update: bool) -> Result<(), String> {
+    let failed = |e: io::Error| format!("failed to access snapshot `{}`: {}", path.display(), e);
+
+    if update {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(failed)?;
+        }
+
+        return fs::write(path, actual).map_err(failed);
+    }
+
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) => expected,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Err(format!(
+                "snapshot `{}` is missing, set {}=1 to create it\n\n{}",
+                path.display(),
+                UPDATE_SNAPSHOTS,
+                actual
+            ))
+        }
+        Err(e) => return Err(failed(e)),
+    };
+
+    if expected == actual {
+        return Ok(());
+    }
+
+    Err(format!(
+        "snapshot `{}` doesn't match, set {}=1 to update it\n\n{}",
+        path.display(),
+        UPDATE_SNAPSHOTS,
+        diff(&expected, actual)
+    ))
+}
+
+/// The lines of `expected` and `actual` marked as removed with `-`, added with `+` or unchanged,
+/// such that as many lines as possible are unchanged.
+fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<_> = expected.lines().collect();
+    let new: Vec<_> = actual.lines().collect();
+
+    // The length of the longest common subsequence of `old[i..]` and `new[j..]`.
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = match old[i] == new[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff += &format!("  {}\n", old[i]);
+            i += 1;
+            j += 1;
+        } else if j == new.len() || (i < old.len() && common[i + 1][j] >= common[i][j + 1]) {
+            diff += &format!("- {}\n", old[i]);
+            i += 1;
+        } else {
+            diff += &format!("+ {}\n", new[j]);
+            j += 1;
+        }
+    }
+
+    diff
+}
+
+/// Asserts that the dump of a widget tree matches the snapshot `snapshots/<name>.snap`
+/// relative to the root of the crate being tested.
+///
+/// Snapshots are created or updated instead if the environment variable
+/// `OXIDIZER_UPDATE_SNAPSHOTS` is set, see [`snapshot`](crate::snapshot) for the format.
+///
+/// ## Example
+/// ```rust,no_run
+/// use oxidizer::{assert_widget_snapshot, widget, Widget};
+///
+/// let ui: Widget<()> = widget!(Column [ Button { label: "Ok" } ]);
+/// assert_widget_snapshot!("dialog", &ui);
+/// ```
+#[macro_export]
+macro_rules! assert_widget_snapshot {
+    ($name:expr, $widget:expr $(,)?) => {
+        $crate::snapshot::assert_snapshot(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("snapshots")
+                .join(format!("{}.snap", $name)),
+            $widget,
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{event::*, widget, Component};
+    use proptest::{collection::vec, prelude::*};
+    use std::convert::TryFrom;
+    use std::sync::Arc;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    struct Counter;
+
+    struct Never;
+
+    impl TryFrom<()> for Never {
+        type Error = ();
+
+        fn try_from(action: ()) -> Result<Self, ()> {
+            Err(action)
+        }
+    }
+
+    impl Component<()> for Counter {
+        type State = ();
+        type Message = Never;
+
+        fn update(&self, _: &mut (), _: Never) -> Option<()> {
+            None
+        }
+
+        fn view(&self, _: &()) -> Widget<'static, ()> {
+            widget!(Button)
+        }
+    }
+
+    fn todo() -> Widget<'static, ()> {
+        let clicked: fn(&Button<()>, &Clicked) = |_, _| ();
+        let entered: fn(&Entry<()>, &Entered) = |_, _| ();
+
+        widget!(Column [
+            Row [
+                Entry { value: "milk", handler: entered }
+                Button { label: "Add \"Todo\"", handler: clicked }
+            ]
+            Checkbox { label: "buy milk" }
+            Stateful { key: "a", component: Counter }
+        ])
+    }
+
+    fn path(name: &str) -> std::path::PathBuf {
+        env::temp_dir()
+            .join(format!("oxidizer-snapshot-{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn dump() {
+        let expected = "Column\n  \
+                          Row\n    \
+                            Entry(\"milk\", handler)\n    \
+                            Button(\"Add \\\"Todo\\\"\", handler)\n  \
+                          Checkbox(\"buy milk\", false)\n  \
+                          Stateful(\"a\", Counter)\n";
+
+        assert_eq!(Dump(&todo()).to_string(), expected);
+    }
+
+    #[test]
+    fn classes_and_flags() {
+        let scrolled: fn(&Scroll<()>, &Scrolled) = |_, _| ();
+        let ui: Widget<()> = widget!(Column { style: "dialog" } [
+            Row { style: "toolbar dense", disabled: true } [ Entry { style: "search", readonly: true } ]
+            Button { label: "Ok", style: "primary", disabled: true }
+            Checkbox { style: "small" }
+            Scroll { style: "list", offset: (0, 120), handler: scrolled, disabled: true } [ Button ]
+        ]);
+
+        let expected = "Column.dialog\n  \
+                          Row.toolbar.dense(disabled)\n    \
+                            Entry.search(\"\", readonly)\n  \
+                          Button.primary(\"Ok\", disabled)\n  \
+                          Checkbox.small(\"\", false)\n  \
+                          Scroll.list(0, 120, handler, disabled)\n    \
+                            Button(\"\")\n";
+
+        assert_eq!(Dump(&ui).to_string(), expected);
+    }
+
+    #[test]
+    fn virtual_lists() {
+        let row: fn(usize) -> Widget<'static, ()> = |i| {
+            widget!(Text {
+                content: i.to_string()
+            })
+        };
+        let scrolled: fn(&VirtualList<()>, &Scrolled) = |_, _| ();
+
+        let mut list = VirtualList::new(100, 20, row).viewport(40).scroll(30);
+        list.handler = Some(scrolled);
+        list.disabled = true;
+
+        let expected = "VirtualList(100, 1..4, handler, disabled)\n  \
+                          Text(\"1\")\n  \
+                          Text(\"2\")\n  \
+                          Text(\"3\")\n";
+
+        assert_eq!(Dump(&list.into()).to_string(), expected);
+    }
+
+    #[test]
+    fn tables_and_grids() {
+        let mut table: Table<()> = Table::new([TableColumn::new("Name"), TableColumn::new("Size")])
+            .row([widget!(Text { content: "a.txt" })]);
+
+        let sorted: fn(&Table<()>, &Sorted) = |_, _| ();
+        table.sort = Some((1, Order::Descending));
+        table.selected = Some(0);
+        table.sort_handler = Some(sorted);
+
+        let mut grid: Grid<()> = Grid::new()
+            .place(Area::new(0, 0), table)
+            .place(Area::new(1, 0).span(1, 2), widget!(Button));
+
+        grid.disabled = true;
+
+        let expected = "Grid(2x2, disabled)\n  \
+                          Table([\"Name\", \"Size\"], sorted 1 desc, selected 0, sort handler)\n    \
+                            Text(\"a.txt\")\n    \
+                            Text(\"\")\n  \
+                          Button(\"\")\n";
+
+        assert_eq!(Dump(&grid.into()).to_string(), expected);
+    }
+
+    #[test]
+    fn stacks_and_tabs() {
+        let mut tabs: Tabs<()> = Tabs::new()
+            .tab("General", widget!(Entry))
+            .tab("Advanced", widget!(Checkbox));
+
+        tabs.active = 1;
+
+        let stack = widget!(Stack { active: 0usize, disabled: true } [ @{ tabs } Button ]);
+
+        let expected = "Stack(0 of 2, disabled)\n  \
+                          Tabs([\"General\", \"Advanced\"], active 1)\n    \
+                            Checkbox(\"\", false)\n";
+
+        assert_eq!(Dump(&stack).to_string(), expected);
+    }
+
+    #[test]
+    fn modals() {
+        let dismissed: fn(&Modal<()>, &Dismissed) = |_, _| ();
+        let modal = |open| {
+            widget!(
+                Modal {
+                    title: "Quit?",
+                    open,
+                    handler: dismissed
+                }[Button]
+            )
+        };
+
+        assert_eq!(
+            Dump(&modal(false)).to_string(),
+            "Modal(\"Quit?\", handler)\n"
+        );
+        assert_eq!(
+            Dump(&modal(true)).to_string(),
+            "Modal(\"Quit?\", open, handler)\n  Button(\"\")\n"
+        );
+    }
+
+    #[test]
+    fn tree_views() {
+        let expanded = TreeNode {
+            expanded: true,
+            ..TreeNode::new("src").with(TreeNode::new("widget").with(TreeNode::new("button.rs")))
+        };
+
+        let tree: TreeView<()> = TreeView {
+            nodes: vec![expanded, TreeNode::new("Cargo.toml")],
+            selected: Some(vec![0, 0]),
+            disabled: true,
+            ..Default::default()
+        };
+
+        let expected =
+            "TreeView([\"src\", \"  widget\", \"Cargo.toml\"], selected 0/0, disabled)\n";
+        assert_eq!(Dump(&tree.into()).to_string(), expected);
+    }
+
+    #[test]
+    fn shared_is_transparent() {
+        let shared = Widget::Shared(Arc::new(Widget::Shared(Arc::new(todo()))));
+        assert_eq!(Dump(&shared).to_string(), Dump(&todo()).to_string());
+    }
+
+    #[test]
+    fn missing_snapshot() {
+        let path = path("missing.snap");
+        assert!(check(&path, "Row\n", false)
+            .unwrap_err()
+            .contains("is missing"));
+    }
+
+    #[test]
+    fn update_and_compare() {
+        let path = path("update.snap");
+
+        assert_eq!(check(&path, "Row\n  Entry(\"\")\n", true), Ok(()));
+        assert_eq!(check(&path, "Row\n  Entry(\"\")\n", false), Ok(()));
+
+        let error = check(&path, "Row\n  Button(\"\")\n", false).unwrap_err();
+        assert!(error.contains("doesn't match"));
+        assert!(error.ends_with("  Row\n-   Entry(\"\")\n+   Button(\"\")\n"));
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn diff_only_marks_lines_that_changed() {
+        assert_eq!(
+            diff(
+                "Row\n  Entry(\"\")\n  Button(\"\")\n",
+                "Row\n  Text(\"\")\n  Entry(\"\")\n  Button(\"\")\n"
+            ),
+            "  Row\n+   Text(\"\")\n    Entry(\"\")\n    Button(\"\")\n"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn diff_keeps_both_sides(expected in vec("[ab]{0,2}", 0..8), actual in vec("[ab]{0,2}", 0..8)) {
+            let diff = diff(&expected.join("\n"), &actual.join("\n"));
+            let side = |marker| diff.lines().filter(|l| !l.starts_with(marker)).map(|l| &l[2..]).collect::<Vec<_>>();
+
+            assert_eq!(side('+'), expected.join("\n").lines().collect::<Vec<_>>());
+            assert_eq!(side('-'), actual.join("\n").lines().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn macro_compares_against_files_in_the_crate() {
+        crate::assert_widget_snapshot!("todo", &todo());
+    }
+}
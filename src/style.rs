@@ -0,0 +1,373 @@
+//! Backend agnostic styling of widget trees.
+//!
+//! Widgets carry a [`Style`], that is a list of classes such as `"primary"` or `"danger"`, which
+//! a [`Theme`] maps to [`Declarations`] of colors, fonts and spacing.
+//! Widgets inherit the [`Resolved`] values of their parents, on top of which the declarations of
+//! their own classes are applied in order, so styling a [`Row`](crate::widget::Row) or a
+//! [`Column`](crate::widget::Column) styles its descendants as well.
+//! Backends only ever read resolved values.
+//!
+//! ## Example
+//! ```rust
+//! use oxidizer::{style::*, widget, Widget};
+//!
+//! let theme = Theme::default()
+//!     .class("primary", Declarations { color: Some(Color::WHITE), background: Some(Color::rgb(0, 0, 255)), ..Default::default() })
+//!     .class("large", Declarations { spacing: Some(16), ..Default::default() });
+//!
+//! let ui: Widget<()> = widget!(Column { style: "large" } [ Button { style: "primary" } ]);
+//! let styles = theme.resolve_tree(&ui);
+//!
+//! assert_eq!(styles[&vec![0]].background, Color::rgb(0, 0, 255));
+//! assert_eq!(styles[&vec![0]].spacing, 16);
+//! ```
+
+use crate::widget::Widget;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+/// The style classes of a widget.
+///
+/// Converting from a string splits it at whitespaces, so `"primary large"` has two classes.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Style {
+    classes: Vec<String>,
+}
+
+impl Style {
+    /// A style without classes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `class`.
+    pub fn with(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+
+    /// Iterates over the classes in order.
+    pub fn classes(&self) -> impl Iterator<Item = &str> {
+        self.classes.iter().map(String::as_str)
+    }
+
+    /// Whether `class` is one of the classes.
+    pub fn contains(&self, class: &str) -> bool {
+        self.classes().any(|c| c == class)
+    }
+
+    /// Whether there are no classes.
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+}
+
+impl From<&str> for Style {
+    fn from(classes: &str) -> Self {
+        Style {
+            classes: classes.split_whitespace().map(String::from).collect(),
+        }
+    }
+}
+
+impl From<&String> for Style {
+    fn from(classes: &String) -> Self {
+        classes.as_str().into()
+    }
+}
+
+impl From<String> for Style {
+    fn from(classes: String) -> Self {
+        classes.as_str().into()
+    }
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.classes.join(" "))
+    }
+}
+
+/// A color in the sRGB space.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+
+    /// An opaque color.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color::rgba(r, g, b, 255)
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color { r, g, b, a }
+    }
+}
+
+/// A font, whose size is given in logical pixels.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Font {
+    pub family: String,
+    pub size: u16,
+    pub bold: bool,
+}
+
+impl Default for Font {
+    fn default() -> Self {
+        Font {
+            family: "sans-serif".into(),
+            size: 14,
+            bold: false,
+        }
+    }
+}
+
+/// The values a class overrides, the remaining ones are inherited.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Declarations {
+    pub color: Option<Color>,
+    pub background: Option<Color>,
+    pub font: Option<Font>,
+    /// The space between children in logical pixels.
+    pub spacing: Option<u16>,
+    /// The space around contents in logical pixels.
+    pub padding: Option<u16>,
+}
+
+/// The values a backend renders a widget with.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Resolved {
+    pub color: Color,
+    pub background: Color,
+    pub font: Font,
+    /// The space between children in logical pixels.
+    pub spacing: u16,
+    /// The space around contents in logical pixels.
+    pub padding: u16,
+}
+
+impl Default for Resolved {
+    fn default() -> Self {
+        Resolved {
+            color: Color::BLACK,
+            background: Color::WHITE,
+            font: Font::default(),
+            spacing: 4,
+            padding: 4,
+        }
+    }
+}
+
+impl Resolved {
+    fn apply(&mut self, declarations: &Declarations) {
+        self.color = declarations.color.unwrap_or(self.color);
+        self.background = declarations.background.unwrap_or(self.background);
+        self.spacing = declarations.spacing.unwrap_or(self.spacing);
+        self.padding = declarations.padding.unwrap_or(self.padding);
+
+        if let Some(font) = &declarations.font {
+            self.font = font.clone();
+        }
+    }
+}
+
+/// Maps style classes to [`Declarations`] on top of base values.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Theme {
+    base: Resolved,
+    classes: HashMap<String, Declarations>,
+}
+
+impl Theme {
+    /// A theme without classes.
+    pub fn new(base: Resolved) -> Self {
+        Theme {
+            base,
+            classes: HashMap::new(),
+        }
+    }
+
+    /// The values of the root of widget trees before applying its classes.
+    pub fn base(&self) -> &Resolved {
+        &self.base
+    }
+
+    /// Declares the values of `class`, replacing previous declarations.
+    pub fn class(mut self, class: impl Into<String>, declarations: Declarations) -> Self {
+        self.classes.insert(class.into(), declarations);
+        self
+    }
+
+    /// Applies the declarations of the classes in `style` on top of `inherited`.
+    ///
+    /// Later classes take precedence and unknown classes are ignored.
+    pub fn resolve(&self, inherited: &Resolved, style: &Style) -> Resolved {
+        let mut resolved = inherited.clone();
+
+        for declarations in style.classes().filter_map(|c| self.classes.get(c)) {
+            resolved.apply(declarations);
+        }
+
+        resolved
+    }
+
+    /// Resolves the values of every widget in `widget`, indexed by their paths.
+    pub fn resolve_tree<A>(&self, widget: &Widget<A>) -> HashMap<Vec<usize>, Resolved> {
+        let mut resolved = HashMap::new();
+        self.visit(&mut Vec::new(), widget, &self.base, &mut resolved);
+        resolved
+    }
+
+    fn visit<A>(
+        &self,
+        path: &mut Vec<usize>,
+        widget: &Widget<A>,
+        inherited: &Resolved,
+        resolved: &mut HashMap<Vec<usize>, Resolved>,
+    ) {
+        let values = match widget.style() {
+            Some(style) => self.resolve(inherited, style),
+            None => inherited.clone(),
+        };
+
+        for (i, child) in widget.indices().zip(widget.children()) {
+            path.push(i);
+            self.visit(path, child, &values, resolved);
+            path.pop();
+        }
+
+        resolved.insert(path.clone(), values);
+    }
+}
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, prelude::*};
+
+#[cfg(test)]
+impl Arbitrary for Style {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let class = prop::sample::select(vec!["primary", "danger", "large"]);
+
+        prop::collection::vec(class, 0..=2)
+            .prop_map(|classes| classes.join(" ").into())
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget;
+    use std::sync::Arc;
+
+    fn count(w: &Widget<()>) -> usize {
+        1 + w.children().iter().map(count).sum::<usize>()
+    }
+
+    fn theme() -> Theme {
+        Theme::default()
+            .class(
+                "primary",
+                Declarations {
+                    color: Some(Color::WHITE),
+                    background: Some(Color::rgb(0, 0, 255)),
+                    ..Default::default()
+                },
+            )
+            .class(
+                "danger",
+                Declarations {
+                    background: Some(Color::rgb(255, 0, 0)),
+                    ..Default::default()
+                },
+            )
+            .class(
+                "large",
+                Declarations {
+                    font: Some(Font {
+                        size: 24,
+                        ..Default::default()
+                    }),
+                    spacing: Some(16),
+                    ..Default::default()
+                },
+            )
+    }
+
+    proptest! {
+        #[test]
+        fn classes_are_split_at_whitespaces(classes: Vec<String>) {
+            let classes: Vec<_> = classes.iter().flat_map(|c| c.split_whitespace()).map(String::from).collect();
+            let style = Style::from(classes.join(" \t\n"));
+
+            assert_eq!(style.classes().collect::<Vec<_>>(), classes);
+            assert_eq!(Style::from(style.to_string()), style);
+            assert_eq!(classes.iter().fold(Style::new(), |s, c| s.with(c.as_str())), style);
+        }
+
+        #[test]
+        fn later_classes_take_precedence(style: Style) {
+            let resolved = theme().resolve(theme().base(), &style);
+
+            let background = style.classes().filter_map(|c| match c {
+                "primary" => Some(Color::rgb(0, 0, 255)),
+                "danger" => Some(Color::rgb(255, 0, 0)),
+                _ => None,
+            }).last();
+
+            assert_eq!(resolved.background, background.unwrap_or(Color::WHITE));
+            assert_eq!(resolved.color, if style.contains("primary") { Color::WHITE } else { Color::BLACK });
+            assert_eq!(resolved.spacing, if style.contains("large") { 16 } else { 4 });
+        }
+
+        #[test]
+        fn every_widget_is_resolved(w: Widget<()>) {
+            let resolved = theme().resolve_tree(&w);
+
+            assert_eq!(resolved.len(), count(&w));
+            assert_eq!(theme().resolve_tree(&Widget::Shared(Arc::new(w))), resolved);
+        }
+    }
+
+    #[test]
+    fn values_are_inherited() {
+        let ui: Widget<()> = widget!(Column { style: "primary" } [
+            Row { style: "large" } [ Button { style: "danger" } Entry ]
+            Checkbox
+        ]);
+
+        let resolved = theme().resolve_tree(&ui);
+        let primary = theme().resolve(theme().base(), &"primary".into());
+        let large = theme().resolve(&primary, &"large".into());
+
+        assert_eq!(resolved[&vec![]], primary);
+        assert_eq!(resolved[&vec![0]], large);
+        assert_eq!(
+            resolved[&vec![0, 0]],
+            theme().resolve(&large, &"danger".into())
+        );
+        assert_eq!(resolved[&vec![0, 0]].color, Color::WHITE);
+        assert_eq!(resolved[&vec![0, 0]].background, Color::rgb(255, 0, 0));
+        assert_eq!(resolved[&vec![0, 1]], large);
+        assert_eq!(resolved[&vec![1]], primary);
+    }
+
+    #[test]
+    fn unknown_classes_are_ignored() {
+        let base = Theme::default();
+        assert_eq!(
+            base.resolve(base.base(), &"primary".into()),
+            Resolved::default()
+        );
+    }
+}
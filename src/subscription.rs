@@ -0,0 +1,368 @@
+use crate::executor::Unpark;
+use crate::runtime::Outcome;
+use futures_core::Stream;
+use std::fmt;
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender, TryRecvError};
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, Thread, ThreadId};
+use std::time::{Duration, Instant};
+
+/// How often [`Subscription::receiver`] checks whether it has been stopped, as receivers can't
+/// be woken up by anything but their senders.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The means by which a running subscription emits actions.
+pub struct Sink<A> {
+    sender: Sender<Outcome<A>>,
+    stopped: Receiver<()>,
+}
+
+impl<A> Sink<A> {
+    /// Emits `action`, returns `false` if the subscription has been stopped.
+    pub fn send(&self, action: A) -> bool {
+        !self.is_stopped() && self.sender.send(Outcome::Emitted(action)).is_ok()
+    }
+
+    /// Whether the subscription has been stopped.
+    pub fn is_stopped(&self) -> bool {
+        matches!(self.stopped.try_recv(), Err(TryRecvError::Disconnected))
+    }
+
+    /// Sleeps for `duration`, returns `false` if the subscription is stopped in the meantime.
+    pub fn sleep(&self, duration: Duration) -> bool {
+        matches!(
+            self.stopped.recv_timeout(duration),
+            Err(RecvTimeoutError::Timeout)
+        )
+    }
+}
+
+struct Recipe<A> {
+    key: String,
+    run: Box<dyn FnOnce(Sink<A>) + Send>,
+}
+
+/// Reports to the runtime that a source is done once dropped by its thread, whether the source
+/// returns or panics.
+struct Finished<A>(Sender<Outcome<A>>);
+
+impl<A> Drop for Finished<A> {
+    fn drop(&mut self) {
+        // The receiver is only gone if the runtime has been dropped.
+        self.0.send(Outcome::Finished(thread::current().id())).ok();
+    }
+}
+
+/// A running subscription, which is stopped when dropped.
+pub(crate) struct Running {
+    stop: Option<Sender<()>>,
+    thread: Thread,
+}
+
+impl Running {
+    /// Identifies the thread the source runs on.
+    pub(crate) fn id(&self) -> ThreadId {
+        self.thread.id()
+    }
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        self.stop.take();
+        self.thread.unpark();
+    }
+}
+
+/// A set of long-lived sources of actions, such as timers and external event streams.
+///
+/// Subscriptions are declared as a function of the application state by
+/// [`Application::subscriptions`](crate::Application::subscriptions).
+/// The [`Runtime`](crate::Runtime) starts a subscription when its key first appears and stops it
+/// as soon as the key disappears, every source runs on a dedicated thread meanwhile.
+/// A source that finishes on its own, such as a stream that ends, is no longer running, but is
+/// started again on the next update if its key is still declared.
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::Subscription;
+/// use std::time::Duration;
+///
+/// enum Action {
+///     Refresh,
+///     // ...
+/// }
+///
+/// let subscription = Subscription::every("refresh", Duration::from_secs(5), |_| Action::Refresh);
+/// assert_eq!(subscription.keys().collect::<Vec<_>>(), vec!["refresh"]);
+/// ```
+pub struct Subscription<A>(Vec<Recipe<A>>);
+
+impl<A: 'static + Send> Subscription<A> {
+    /// A subscription that does nothing.
+    pub fn none() -> Self {
+        Subscription(Vec::new())
+    }
+
+    /// Runs `f` on a dedicated thread, which emits actions through a [`Sink`] until stopped.
+    pub fn run(key: impl Into<String>, f: impl FnOnce(Sink<A>) + Send + 'static) -> Self {
+        Subscription(vec![Recipe {
+            key: key.into(),
+            run: Box::new(f),
+        }])
+    }
+
+    /// Emits the action returned by `f` every `period`.
+    pub fn every(key: impl Into<String>, period: Duration, f: fn(Instant) -> A) -> Self {
+        Subscription::run(key, move |sink| {
+            while sink.sleep(period) && sink.send(f(Instant::now())) {}
+        })
+    }
+
+    /// Emits the actions received from the channel returned by `start`, until every sender is
+    /// gone.
+    ///
+    /// The channel is polled every 50ms for whether the subscription has been stopped, such that
+    /// its thread may linger as long after, though no action is emitted once stopped.
+    pub fn receiver(
+        key: impl Into<String>,
+        start: impl FnOnce() -> Receiver<A> + Send + 'static,
+    ) -> Self {
+        Subscription::run(key, move |sink| {
+            let receiver = start();
+
+            while !sink.is_stopped() {
+                match receiver.recv_timeout(POLL_INTERVAL) {
+                    Ok(action) => {
+                        if !sink.send(action) {
+                            break;
+                        }
+                    }
+
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+        })
+    }
+
+    /// Emits the items of the stream returned by `start`, until it ends.
+    pub fn stream<S>(key: impl Into<String>, start: impl FnOnce() -> S + Send + 'static) -> Self
+    where
+        S: Stream<Item = A>,
+    {
+        Subscription::run(key, move |sink| {
+            let mut stream = Box::pin(start());
+            let waker = Waker::from(Arc::new(Unpark(thread::current())));
+            let mut context = Context::from_waker(&waker);
+
+            while !sink.is_stopped() {
+                match stream.as_mut().poll_next(&mut context) {
+                    Poll::Ready(Some(action)) => {
+                        if !sink.send(action) {
+                            break;
+                        }
+                    }
+
+                    Poll::Ready(None) => break,
+                    Poll::Pending => thread::park(),
+                }
+            }
+        })
+    }
+
+    /// A subscription to all `subscriptions`.
+    pub fn batch(subscriptions: impl IntoIterator<Item = Self>) -> Self {
+        Subscription(subscriptions.into_iter().flat_map(|s| s.0).collect())
+    }
+}
+
+impl<A> Subscription<A> {
+    /// The keys that identify each source.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|r| r.key.as_str())
+    }
+
+    /// Starts every source whose key is not in `running`, stops sources whose key is missing.
+    ///
+    /// Sources with duplicate keys are ignored.
+    pub(crate) fn reconcile(self, running: &mut Vec<(String, Running)>, sender: &Sender<Outcome<A>>)
+    where
+        A: 'static + Send,
+    {
+        let mut previous = std::mem::take(running);
+
+        for Recipe { key, run } in self.0 {
+            if running.iter().any(|(k, _)| *k == key) {
+                continue;
+            }
+
+            match previous.iter().position(|(k, _)| *k == key) {
+                Some(i) => running.push(previous.swap_remove(i)),
+                None => {
+                    let (stop, stopped) = channel();
+                    let finished = Finished(sender.clone());
+                    let sink = Sink {
+                        sender: sender.clone(),
+                        stopped,
+                    };
+
+                    let thread = thread::spawn(move || {
+                        let _finished = finished;
+                        run(sink)
+                    });
+
+                    let thread = thread.thread().clone();
+
+                    let handle = Running {
+                        stop: Some(stop),
+                        thread,
+                    };
+
+                    running.push((key, handle));
+                }
+            }
+        }
+    }
+}
+
+impl<A: 'static + Send> Default for Subscription<A> {
+    fn default() -> Self {
+        Subscription::none()
+    }
+}
+
+impl<A> fmt::Debug for Subscription<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Subscription")
+            .field(&self.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::pin::Pin;
+
+    struct Iter<I>(I);
+
+    impl<I: Iterator + Unpin> Stream for Iter<I> {
+        type Item = I::Item;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.0.next())
+        }
+    }
+
+    fn emitted(outcome: Outcome<u32>) -> Option<u32> {
+        match outcome {
+            Outcome::Emitted(action) => Some(action),
+            Outcome::Finished(_) => None,
+            _ => panic!("expected an emitted action"),
+        }
+    }
+
+    fn start(subscription: Subscription<u32>) -> (Vec<(String, Running)>, Receiver<Outcome<u32>>) {
+        let (sender, receiver) = channel();
+        let mut running = Vec::new();
+        subscription.reconcile(&mut running, &sender);
+        (running, receiver)
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(Subscription::<()>::none().keys().count(), 0);
+        assert_eq!(Subscription::<()>::default().keys().count(), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn batch(keys: Vec<String>) {
+            let subscription = Subscription::<()>::batch(keys.iter().map(|k| Subscription::run(k.clone(), |_| {})));
+            assert_eq!(subscription.keys().collect::<Vec<_>>(), keys);
+            assert_eq!(format!("{:?}", subscription), format!("Subscription({:?})", keys));
+        }
+
+        #[test]
+        fn every(n in 1usize..5) {
+            let (running, receiver) = start(Subscription::every("", Duration::from_millis(1), |_| 42));
+            assert_eq!(receiver.iter().take(n).map_while(emitted).collect::<Vec<_>>(), vec![42; n]);
+            drop(running);
+        }
+
+        #[test]
+        fn receiver(ns: Vec<u32>) {
+            let items = ns.clone();
+            let (running, receiver) = start(Subscription::receiver("", move || {
+                let (tx, rx) = channel();
+                items.into_iter().for_each(|n| tx.send(n).unwrap());
+                rx
+            }));
+
+            assert_eq!(receiver.iter().map_while(emitted).collect::<Vec<_>>(), ns);
+            drop(running);
+        }
+
+        #[test]
+        fn stream(ns: Vec<u32>) {
+            let items = ns.clone();
+            let (running, receiver) = start(Subscription::stream("", move || Iter(items.into_iter())));
+            assert_eq!(receiver.iter().map_while(emitted).collect::<Vec<_>>(), ns);
+            drop(running);
+        }
+
+        #[test]
+        fn sources_report_when_they_finish(ns: Vec<u32>) {
+            let items = ns.clone();
+            let (running, receiver) = start(Subscription::receiver("", move || {
+                let (tx, rx) = channel();
+                items.into_iter().for_each(|n| tx.send(n).unwrap());
+                rx
+            }));
+
+            let mut outcomes = receiver.iter().skip(ns.len());
+            assert!(matches!(outcomes.next(), Some(Outcome::Finished(id)) if id == running[0].1.id()));
+        }
+
+        #[test]
+        fn sources_stop_when_their_key_disappears(keys: Vec<String>) {
+            let declare = |keys: &[String]| {
+                Subscription::batch(keys.iter().map(|k| Subscription::run(k.clone(), |sink: Sink<u32>| {
+                    while sink.sleep(Duration::from_secs(60)) {}
+                })))
+            };
+
+            let (mut running, receiver) = start(declare(&keys));
+
+            let mut unique = keys.clone();
+            unique.sort();
+            unique.dedup();
+            assert_eq!(running.len(), unique.len());
+
+            let (sender, _) = channel();
+            declare(&[]).reconcile(&mut running, &sender);
+            assert!(running.is_empty());
+
+            // Every source has stopped once all senders are gone.
+            drop(sender);
+            assert!(receiver.iter().all(|o| matches!(o, Outcome::Finished(_))));
+        }
+
+        #[test]
+        fn sources_keep_running_while_their_key_is_present(key: String) {
+            let (sender, receiver) = channel();
+            let mut running = Vec::new();
+
+            Subscription::run(key.clone(), |sink| { sink.send(0); }).reconcile(&mut running, &sender);
+            Subscription::run(key.clone(), |sink| { sink.send(1); }).reconcile(&mut running, &sender);
+
+            drop(sender);
+            assert_eq!(running.len(), 1);
+            assert_eq!(receiver.recv().map(emitted), Ok(Some(0)));
+            drop(running);
+            assert_eq!(receiver.iter().map_while(emitted).count(), 0);
+        }
+    }
+}
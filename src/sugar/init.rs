@@ -1,13 +1,53 @@
+/// Initializes a struct from a partial list of properties, converting their values and leaving
+/// the remaining fields to their defaults.
+///
+/// Properties are assigned one by one, so that the compiler reports unknown properties and values
+/// of the wrong type on the tokens of the property itself, suggesting similarly named properties.
+///
+/// ```compile_fail,E0609
+/// # use oxidizer::{widget, Widget};
+/// let _: Widget<()> = widget!(Button { lable: "Ok" });
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use oxidizer::{widget, Widget};
+/// let _: Widget<()> = widget!(Checkbox { value: "yes" });
+/// ```
+///
+/// Names that are neither builtin widgets nor in scope are reported on the name itself, while
+/// [`widget!`](macro@crate::widget) refuses children of widgets that can't have any and tokens
+/// that aren't widgets at all.
+///
+/// ```compile_fail,E0422
+/// # use oxidizer::{widget, Widget};
+/// let _: Widget<()> = widget!(Column [ Buton ]);
+/// ```
+///
+/// ```compile_fail
+/// # use oxidizer::{widget, Widget};
+/// let _: Widget<()> = widget!(Column [ Button [ Entry ] ]);
+/// ```
+///
+/// ```compile_fail
+/// # use oxidizer::{widget, Widget};
+/// let _: Widget<()> = widget!(Column [ "Ok" ]);
+/// ```
 #[doc(hidden)]
 #[macro_export]
 macro_rules! init {
     ( $t:path $({ $($p:ident: $v:expr $(, $ps:ident: $vs:expr)* $(,)?)? })? ) => {
         {
-            #[allow(clippy::needless_update)]
-            $t {
-                $($($p: $v.into() $(, $ps: $vs.into())*,)*)*
-                ..Default::default()
-            }
+            #[allow(unused_mut, clippy::field_reassign_with_default)]
+            let mut init = {
+                #[allow(clippy::needless_update)]
+                $t { ..::core::default::Default::default() }
+            };
+
+            $($(
+                init.$p = ::core::convert::Into::into($v);
+                $(init.$ps = ::core::convert::Into::into($vs);)*
+            )*)*
+            init
         }
     };
 
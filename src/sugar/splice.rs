@@ -0,0 +1,37 @@
+//! Splicing of expressions into the children of widgets declared through [`widget!`](macro@crate::widget).
+//!
+//! An expression spliced with `@{ expr }` is either converted into a single widget or, failing
+//! that, iterated over and each of its items converted into a widget.
+//! The choice is made through method resolution, which prefers [`SpliceWidget`] by value over
+//! [`SpliceIter`] by mutable reference.
+
+use crate::Widget;
+
+/// An expression spliced into a list of children.
+pub struct Splice<T>(pub Option<T>);
+
+/// Splices an expression that converts into a widget.
+pub trait SpliceWidget<'w, A> {
+    fn splice_into(self, children: &mut Vec<Widget<'w, A>>);
+}
+
+impl<'w, A: 'w, T: Into<Widget<'w, A>>> SpliceWidget<'w, A> for Splice<T> {
+    fn splice_into(self, children: &mut Vec<Widget<'w, A>>) {
+        children.extend(self.0.map(Into::into))
+    }
+}
+
+/// Splices an expression that iterates over widgets.
+pub trait SpliceIter<'w, A> {
+    fn splice_into(self, children: &mut Vec<Widget<'w, A>>);
+}
+
+impl<'w, A: 'w, I> SpliceIter<'w, A> for &mut Splice<I>
+where
+    I: IntoIterator,
+    I::Item: Into<Widget<'w, A>>,
+{
+    fn splice_into(self, children: &mut Vec<Widget<'w, A>>) {
+        children.extend(self.0.take().into_iter().flatten().map(Into::into))
+    }
+}
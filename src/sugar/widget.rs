@@ -3,9 +3,17 @@
 /// This macro allows declaring a tree of widgets without all the verbosity required to initialize
 /// structs in Rust.
 ///
+/// Besides the widgets in this crate, any type in scope that implements
+/// [`CustomWidget`](crate::widget::CustomWidget), typically through `#[derive(Widget)]`,
+/// may be declared by name.
+///
+/// Within the brackets that enclose children, widgets may also be declared conditionally with
+/// `@if cond { ... } else { ... }`, repeatedly with `@for item in iter { ... }`, or spliced with
+/// `@{ expr }` from any expression that converts into a widget or iterates over such values.
+///
 /// ## Example
 /// ```rust
-/// use oxidizer::{*, widget::*};
+/// use oxidizer::{*, style::Style, widget::*};
 ///
 /// enum Action { /* ... */ }
 ///
@@ -32,47 +40,116 @@
 ///                     Widget::from(Entry {
 ///                         value: "".to_string(),
 ///                         handler: handler.into(),
+///                         style: Style::new(),
+///                         disabled: false,
+///                         readonly: false,
 ///                     }),
 ///                     Widget::from(Button {
 ///                         label: "Add Todo".to_string(),
 ///                         handler: handler.into(),
+///                         style: Style::new(),
+///                         disabled: false,
 ///                     }),
-///                 ])
+///                 ]),
+///                 style: Style::new(),
+///                 disabled: false,
 ///             }),
 ///             Widget::from(Checkbox {
 ///                 value: false,
 ///                 label: "buy milk".to_string(),
 ///                 handler: handler.into(),
+///                 style: Style::new(),
+///                 disabled: false,
 ///             }),
 ///             Widget::from(Checkbox {
 ///                 value: false,
 ///                 label: "learn oxidizer".to_string(),
 ///                 handler: handler.into(),
+///                 style: Style::new(),
+///                 disabled: false,
 ///             }),
-///         ])
+///         ]),
+///         style: Style::new(),
+///         disabled: false,
 ///     })
 /// );
+///
+/// let todos = vec!["buy milk", "learn oxidizer"];
+/// let header = widget!(Button { label: "Clear", handler });
+///
+/// let ui: Widget<Action> = widget!(
+///     Column [
+///         @if !todos.is_empty() {
+///             @{ header }
+///         }
+///         @for label in todos {
+///             Checkbox { label, handler }
+///         }
+///         @{ (0..3).map(|_| widget!(Entry)) }
+///     ]
+/// );
 /// ```
 #[macro_export]
 macro_rules! widget {
-    ( Row $({ $($ps:tt)* })? $([$($ts:ident $({ $($tps:tt)* })? $([$($tts:tt)*])?)*])? ) => {
+    ( Row $({ $($ps:tt)* })? $([ $($cs:tt)* ])? ) => {
         $crate::Widget::from($crate::init!($crate::widget::Row {
-            children: vec![
-                $($( $crate::widget!($ts $({ $($tps)* })* $([ $($tts)* ])*), )*)*
-            ],
+            children: $crate::widget!(@children $($($cs)*)*),
             $($($ps)*)*
         }))
     };
 
-    ( Column $({ $($ps:tt)* })? $([$($ts:ident $({ $($tps:tt)* })? $([$($tts:tt)*])?)*])? ) => {
+    ( Column $({ $($ps:tt)* })? $([ $($cs:tt)* ])? ) => {
         $crate::Widget::from($crate::init!($crate::widget::Column {
-            children: vec![
-                $($( $crate::widget!($ts $({ $($tps)* })* $([ $($tts)* ])*), )*)*
-            ],
+            children: $crate::widget!(@children $($($cs)*)*),
+            $($($ps)*)*
+        }))
+    };
+
+    ( Scroll $({ $($ps:tt)* })? $([ $($cs:tt)* ])? ) => {
+        $crate::Widget::from($crate::init!($crate::widget::Scroll {
+            children: $crate::widget!(@children $($($cs)*)*),
+            $($($ps)*)*
+        }))
+    };
+
+    ( Stack $({ $($ps:tt)* })? $([ $($cs:tt)* ])? ) => {
+        $crate::Widget::from($crate::init!($crate::widget::Stack {
+            children: $crate::widget!(@children $($($cs)*)*),
+            $($($ps)*)*
+        }))
+    };
+
+    ( Modal $({ $($ps:tt)* })? $([ $($cs:tt)* ])? ) => {
+        $crate::Widget::from($crate::init!($crate::widget::Modal {
+            children: $crate::widget!(@children $($($cs)*)*),
             $($($ps)*)*
         }))
     };
 
+    ( Button $({ $($ps:tt)* })? [ $($cs:tt)* ] ) => {
+        compile_error!("Button can't have children")
+    };
+
+    ( Entry $({ $($ps:tt)* })? [ $($cs:tt)* ] ) => {
+        compile_error!("Entry can't have children")
+    };
+
+    ( Checkbox $({ $($ps:tt)* })? [ $($cs:tt)* ] ) => {
+        compile_error!("Checkbox can't have children")
+    };
+
+    ( TreeView $({ $($ps:tt)* })? [ $($cs:tt)* ] ) => {
+        compile_error!("TreeView can't have children")
+    };
+
+    ( Text $({ $($ps:tt)* })? [ $($cs:tt)* ] ) => {
+        compile_error!("Text can't have children")
+    };
+
+    ( Stateful $({ $($ps:tt)* })? [ $($cs:tt)* ] ) => {
+        compile_error!("Stateful can't have children")
+    };
+
     ( Button $({ $($ps:tt)* })? ) => {
         $crate::Widget::from($crate::init!($crate::widget::Button $({ $($ps)* })*))
     };
@@ -84,6 +161,136 @@ macro_rules! widget {
     ( Checkbox $({ $($ps:tt)* })? ) => {
         $crate::Widget::from($crate::init!($crate::widget::Checkbox $({ $($ps)* })*))
     };
+
+    ( TreeView $({ $($ps:tt)* })? ) => {
+        $crate::Widget::from($crate::init!($crate::widget::TreeView $({ $($ps)* })*))
+    };
+
+    ( Text $({ $($ps:tt)* })? ) => {
+        $crate::Widget::from($crate::init!($crate::widget::Text $({ $($ps)* })*))
+    };
+
+    ( Stateful $({ $($ps:tt)* })? ) => {
+        $crate::Widget::from($crate::init!($crate::widget::Stateful $({ $($ps)* })*))
+    };
+
+    ( $t:ident $({ $($ps:tt)* })? ) => {
+        $crate::Widget::from($crate::init!($t $({ $($ps)* })*))
+    };
+
+    ( $t:ident $({ $($ps:tt)* })? [ $($cs:tt)* ] ) => {
+        $crate::Widget::from(
+            $crate::widget::Custom::new($crate::init!($t $({ $($ps)* })*))
+                .with_children($crate::widget!(@children $($cs)*)),
+        )
+    };
+
+    // Lists of plain widgets need no intermediate vector.
+    ( @children $($ts:ident $({ $($tps:tt)* })? $([$($tts:tt)*])?)* ) => {
+        vec![
+            $( $crate::widget!($ts $({ $($tps)* })* $([ $($tts)* ])*), )*
+        ]
+    };
+
+    ( @children $($cs:tt)* ) => {
+        {
+            #[allow(unused_mut, clippy::vec_init_then_push)]
+            let mut children: ::std::vec::Vec<$crate::Widget<_>> = ::std::vec::Vec::new();
+            $crate::widget!(@push children $($cs)*);
+            children
+        }
+    };
+
+    ( @push $v:ident ) => {};
+
+    ( @push $v:ident @if $($rest:tt)* ) => {
+        $crate::widget!(@if $v [] $($rest)*);
+    };
+
+    ( @push $v:ident @for $p:pat in $($rest:tt)* ) => {
+        $crate::widget!(@for $v ($p) [] $($rest)*);
+    };
+
+    ( @push $v:ident @{ $($e:tt)* } $($rest:tt)* ) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::splice::{SpliceIter as _, SpliceWidget as _};
+            $crate::splice::Splice(Some({ $($e)* })).splice_into(&mut $v);
+        }
+
+        $crate::widget!(@push $v $($rest)*);
+    };
+
+    ( @push $v:ident $t:ident { $($ps:tt)* } [ $($cs:tt)* ] $($rest:tt)* ) => {
+        $v.push($crate::widget!($t { $($ps)* } [ $($cs)* ]));
+        $crate::widget!(@push $v $($rest)*);
+    };
+
+    ( @push $v:ident $t:ident { $($ps:tt)* } $($rest:tt)* ) => {
+        $v.push($crate::widget!($t { $($ps)* }));
+        $crate::widget!(@push $v $($rest)*);
+    };
+
+    ( @push $v:ident $t:ident [ $($cs:tt)* ] $($rest:tt)* ) => {
+        $v.push($crate::widget!($t [ $($cs)* ]));
+        $crate::widget!(@push $v $($rest)*);
+    };
+
+    ( @push $v:ident $t:ident $($rest:tt)* ) => {
+        $v.push($crate::widget!($t));
+        $crate::widget!(@push $v $($rest)*);
+    };
+
+    ( @push $v:ident $t:tt $($rest:tt)* ) => {
+        compile_error!(concat!("expected a widget, found `", stringify!($t), "`"))
+    };
+
+    // The condition is munched token by token up to the block that follows it.
+    ( @if $v:ident [ $($c:tt)+ ] { $($then:tt)* } else @if $($rest:tt)* ) => {
+        if $($c)+ {
+            $crate::widget!(@push $v $($then)*);
+        } else {
+            $crate::widget!(@if $v [] $($rest)*);
+        }
+    };
+
+    ( @if $v:ident [ $($c:tt)+ ] { $($then:tt)* } else { $($else:tt)* } $($rest:tt)* ) => {
+        if $($c)+ {
+            $crate::widget!(@push $v $($then)*);
+        } else {
+            $crate::widget!(@push $v $($else)*);
+        }
+
+        $crate::widget!(@push $v $($rest)*);
+    };
+
+    ( @if $v:ident [ $($c:tt)+ ] { $($then:tt)* } $($rest:tt)* ) => {
+        if $($c)+ {
+            $crate::widget!(@push $v $($then)*);
+        }
+
+        $crate::widget!(@push $v $($rest)*);
+    };
+
+    ( @if $v:ident [ $($c:tt)* ] $t:tt $($rest:tt)* ) => {
+        $crate::widget!(@if $v [ $($c)* $t ] $($rest)*);
+    };
+
+    ( @for $v:ident ($p:pat) [ $($i:tt)+ ] { $($body:tt)* } $($rest:tt)* ) => {
+        for $p in $($i)+ {
+            $crate::widget!(@push $v $($body)*);
+        }
+
+        $crate::widget!(@push $v $($rest)*);
+    };
+
+    ( @for $v:ident ($p:pat) [ $($i:tt)* ] $t:tt $($rest:tt)* ) => {
+        $crate::widget!(@for $v ($p) [ $($i)* $t ] $($rest)*);
+    };
+
+    ( $($ts:tt)* ) => {
+        compile_error!(concat!("expected a widget, found `", stringify!($($ts)*), "`"))
+    };
 }
 
 #[cfg(test)]
@@ -127,7 +334,8 @@ mod tests {
                             Button { label: label.clone() }
                             Checkbox { label: label, value }
                         ])
-                    ])
+                    ]),
+                    ..Default::default()
                 })
             );
         }
@@ -167,11 +375,149 @@ mod tests {
                             Button { label: label.clone() }
                             Checkbox { label: label, value }
                         ])
-                    ])
+                    ]),
+                    ..Default::default()
+                })
+            );
+        }
+
+        #[test]
+        fn scroll_can_have_children(label: String, offset: (u32, u32)) {
+            assert_eq!(widget!(Scroll), Widget::Scroll::<()>(Default::default()));
+
+            assert_eq!(
+                widget!(Scroll { offset } [ Entry Button { label: label.clone() } ]),
+                Widget::from(Scroll::<()> {
+                    children: Box::new([widget!(Entry), widget!(Button { label })]),
+                    offset,
+                    ..Default::default()
                 })
             );
         }
 
+        #[test]
+        fn stack_can_have_children(label: String, active: usize) {
+            assert_eq!(widget!(Stack), Widget::Stack::<()>(Default::default()));
+
+            assert_eq!(
+                widget!(Stack { active } [ Entry Button { label: label.clone() } ]),
+                Widget::from(Stack::<()> {
+                    children: Box::new([widget!(Entry), widget!(Button { label })]),
+                    active,
+                    ..Default::default()
+                })
+            );
+        }
+
+        #[test]
+        fn modal_can_have_children(title: String, open: bool) {
+            assert_eq!(widget!(Modal), Widget::Modal::<()>(Default::default()));
+
+            assert_eq!(
+                widget!(Modal { title: title.clone(), open } [ Text Button ]),
+                Widget::from(Modal::<()> {
+                    title,
+                    children: Box::new([widget!(Text), widget!(Button)]),
+                    open,
+                    ..Default::default()
+                })
+            );
+        }
+
+        #[test]
+        fn children_can_be_conditional(flag: bool, other: bool, label: String) {
+            let expected: Widget<()> = if flag {
+                widget!(Row [ Entry Button { label: label.clone() } ])
+            } else if other {
+                widget!(Row [ Entry Checkbox ])
+            } else {
+                widget!(Row [ Entry ])
+            };
+
+            assert_eq!(
+                widget!(Row [
+                    Entry
+                    @if flag {
+                        Button { label: label.clone() }
+                    } else @if other {
+                        Checkbox
+                    }
+                ]),
+                expected
+            );
+
+            let column: Widget<()> = widget!(Column [ @if !flag { Entry } else { Button } Checkbox ]);
+
+            assert_eq!(
+                column,
+                widget!(Column [ @if flag { Button } @if !flag { Entry } Checkbox ])
+            );
+        }
+
+        #[test]
+        fn children_can_be_generated_by_loops(labels: Vec<String>) {
+            let expected: Vec<Widget<()>> = labels
+                .iter()
+                .flat_map(|label| vec![widget!(Button { label }), widget!(Entry)])
+                .chain(std::iter::once(widget!(Checkbox)))
+                .collect();
+
+            assert_eq!(
+                widget!(Column [
+                    @for label in &labels {
+                        Button { label }
+                        Entry
+                    }
+                    Checkbox
+                ]),
+                Widget::from(Column { children: expected.into(), ..Default::default() })
+            );
+
+            let pairs = labels.iter().enumerate();
+
+            assert_eq!(
+                widget!(Row [ @for (i, label) in pairs { @if i % 2 == 0 { Button { label } } } ]),
+                Widget::from(Row {
+                    children: labels
+                        .iter()
+                        .step_by(2)
+                        .map(|label| widget!(Button { label }))
+                        .collect::<Vec<Widget<()>>>()
+                        .into(),
+                    ..Default::default()
+                })
+            );
+        }
+
+        #[test]
+        fn expressions_can_be_spliced(labels: Vec<String>, value: bool) {
+            let buttons = || labels.iter().map(|label| widget!(Button { label }));
+            let entry: Widget<()> = widget!(Entry);
+
+            let expected = Widget::from(Row {
+                children: std::iter::once(entry.clone())
+                    .chain(buttons())
+                    .chain(std::iter::once(widget!(Checkbox { value })))
+                    .chain(buttons())
+                    .chain(std::iter::once(entry.clone()))
+                    .collect::<Vec<_>>()
+                    .into(),
+                ..Default::default()
+            });
+
+            assert_eq!(
+                widget!(Row [
+                    @{ entry.clone() }
+                    @{ buttons() }
+                    @{ Checkbox { value, ..Default::default() } }
+                    @{ buttons().collect::<Vec<_>>() }
+                    @{ Some(&entry) }
+                    @{ None::<Widget<()>> }
+                ]),
+                expected
+            );
+        }
+
         #[test]
         fn button_can_be_created_with_default_properties(_: ()) {
             assert_eq!(widget!(Button), Widget::Button::<()>(Default::default()));
@@ -253,6 +599,25 @@ mod tests {
             );
         }
 
+        #[test]
+        fn stateful_can_be_created_with_default_properties(_: ()) {
+            assert_eq!(widget!(Stateful), Widget::Stateful::<()>(Default::default()));
+            assert_eq!(widget!(Stateful {}), Widget::Stateful::<()>(Default::default()));
+        }
+
+        #[test]
+        fn stateful_optionally_takes_a_key(key: String) {
+            assert_eq!(
+                widget!(Stateful {
+                    key: key.clone()
+                }),
+                Widget::<()>::from(Stateful {
+                    key,
+                    ..Default::default()
+                })
+            );
+        }
+
         #[test]
         fn checkbox_optionally_takes_a_handler(_: ()) {
             let handler: fn(&_, &_) = |_, _| {};
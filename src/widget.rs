@@ -1,40 +1,88 @@
 mod button;
 mod checkbox;
 mod column;
+mod custom;
 mod entry;
+mod grid;
+mod modal;
 mod row;
+mod scroll;
+mod stack;
+mod stateful;
+mod table;
+mod tabs;
+mod text;
+mod tree_view;
+mod virtual_list;
 
 pub use button::*;
 pub use checkbox::*;
 pub use column::*;
+pub use custom::*;
 pub use entry::*;
+pub use grid::*;
+pub use modal::*;
 pub use row::*;
-
-use crate::{Kind, TreePath};
+pub use scroll::*;
+pub use stack::*;
+pub use stateful::*;
+pub use table::*;
+pub use tabs::*;
+pub use text::*;
+pub use tree_view::*;
+pub use virtual_list::*;
+
+use crate::{event::Event, style::Style, Kind, TreePath};
 use maybe_owned::MaybeOwned;
+use std::sync::Arc;
 
 /// The semantic representation of a widget.
+///
+/// [`Widget::Shared`] is transparent to comparison and hashing,
+/// that is a shared widget compares equal to the widget it points to.
 #[derive(derivative::Derivative)]
-#[derivative(
-    Debug(bound = ""),
-    Clone(bound = ""),
-    Eq(bound = ""),
-    PartialEq(bound = ""),
-    Hash(bound = "")
-)]
+#[derivative(Debug(bound = ""), Clone(bound = ""))]
 pub enum Widget<'w, A> {
     Row(MaybeOwned<'w, Row<'w, A>>),
     Column(MaybeOwned<'w, Column<'w, A>>),
+    Scroll(MaybeOwned<'w, Scroll<'w, A>>),
+    VirtualList(MaybeOwned<'w, VirtualList<'w, A>>),
+    Table(MaybeOwned<'w, Table<'w, A>>),
+    Grid(MaybeOwned<'w, Grid<'w, A>>),
+    Stack(MaybeOwned<'w, Stack<'w, A>>),
+    Tabs(MaybeOwned<'w, Tabs<'w, A>>),
+    Modal(MaybeOwned<'w, Modal<'w, A>>),
     Button(MaybeOwned<'w, Button<A>>),
     Entry(MaybeOwned<'w, Entry<A>>),
     Checkbox(MaybeOwned<'w, Checkbox<A>>),
+    TreeView(MaybeOwned<'w, TreeView<A>>),
+    Text(MaybeOwned<'w, Text>),
+    Stateful(MaybeOwned<'w, Stateful<'w, A>>),
+    Shared(Arc<Widget<'w, A>>),
+    Custom(MaybeOwned<'w, Custom<'w, A>>),
 }
 
 impl<'w, A> Widget<'w, A> {
     pub fn get<S: Into<usize>>(&self, path: impl TreePath<Segment = S>) -> Option<&Widget<'w, A>> {
-        path.segments().into_iter().fold(Some(self), |r, i| {
-            r.and_then(|w| w.into_iter().nth(i.into()))
-        })
+        path.segments()
+            .into_iter()
+            .try_fold(self, |w, i| w.child(i.into()))
+    }
+
+    /// The child at the logical index `i`, if it is built.
+    pub fn child(&self, i: usize) -> Option<&Self> {
+        self.children().get(i.checked_sub(self.indices().start)?)
+    }
+
+    /// The logical indices of [`Widget::children`], which only differ from their positions
+    /// for [`VirtualList`]s, [`Stack`]s and [`Tabs`].
+    pub fn indices(&self) -> std::ops::Range<usize> {
+        match self.resolve() {
+            Widget::VirtualList(w) => w.visible(),
+            Widget::Stack(w) => w.visible(),
+            Widget::Tabs(w) => w.visible(),
+            w => 0..w.children().len(),
+        }
     }
 
     pub fn children(&self) -> &[Self] {
@@ -42,22 +90,244 @@ impl<'w, A> Widget<'w, A> {
         match self {
             Row(w) => w,
             Column(w) => w,
+            Scroll(w) => w,
+            VirtualList(w) => w,
+            Table(w) => w,
+            Grid(w) => w,
+            Stack(w) => w,
+            Tabs(w) => w,
+            Modal(w) => w,
+            Stateful(w) => w,
+            Shared(w) => w.children(),
+            Custom(w) => w,
             _ => &[],
         }
     }
+
+    /// The style classes of this widget, if it can be styled.
+    pub fn style(&self) -> Option<&Style> {
+        use Widget::*;
+        match self.resolve() {
+            Row(w) => Some(&w.style),
+            Column(w) => Some(&w.style),
+            Scroll(w) => Some(&w.style),
+            VirtualList(w) => Some(&w.style),
+            Table(w) => Some(&w.style),
+            Grid(w) => Some(&w.style),
+            Stack(w) => Some(&w.style),
+            Tabs(w) => Some(&w.style),
+            Modal(w) => Some(&w.style),
+            Button(w) => Some(&w.style),
+            Entry(w) => Some(&w.style),
+            Checkbox(w) => Some(&w.style),
+            TreeView(w) => Some(&w.style),
+            Text(w) => Some(&w.style),
+            _ => None,
+        }
+    }
+
+    /// Whether this widget refuses events, regardless of its ancestors.
+    pub fn is_disabled(&self) -> bool {
+        use Widget::*;
+        match self.resolve() {
+            Row(w) => w.disabled,
+            Column(w) => w.disabled,
+            Scroll(w) => w.disabled,
+            VirtualList(w) => w.disabled,
+            Table(w) => w.disabled,
+            Grid(w) => w.disabled,
+            Stack(w) => w.disabled,
+            Tabs(w) => w.disabled,
+            Modal(w) => w.disabled,
+            Button(w) => w.disabled,
+            Entry(w) => w.disabled,
+            Checkbox(w) => w.disabled,
+            TreeView(w) => w.disabled,
+            _ => false,
+        }
+    }
+
+    /// Routes `event` to the handler of this widget, if it handles events of that kind.
+    ///
+    /// Disabled widgets and read-only [`Entry`]s refuse events, and so do [`Table`]s, [`TreeView`]s
+    /// and [`Tabs`] for events that target missing columns, rows, nodes or tabs.
+    pub fn handle(&self, event: &Event) -> Option<A> {
+        if self.is_disabled() {
+            return None;
+        }
+
+        use Widget::*;
+        match (self.resolve(), event) {
+            (Button(w), Event::Clicked(e)) => w.handler.handle(w, e),
+            (Entry(w), Event::Entered(e)) if !w.readonly => w.handler.handle(w, e),
+            (Checkbox(w), Event::Toggled(e)) => w.handler.handle(w, e),
+            (TreeView(w), Event::Expanded(e)) if w.contains(&e.path) => {
+                w.expand_handler.handle(w, e)
+            }
+            (TreeView(w), Event::Collapsed(e)) if w.contains(&e.path) => {
+                w.collapse_handler.handle(w, e)
+            }
+            (TreeView(w), Event::Selected(e)) if w.contains(&e.path) => {
+                w.select_handler.handle(w, e)
+            }
+            (Scroll(w), Event::Scrolled(e)) => w.handler.map(|h| h(w, e)),
+            (VirtualList(w), Event::Scrolled(e)) => w.handler.map(|h| h(w, e)),
+            (Table(w), Event::Sorted(e)) if e.value.0 < w.columns().len() => {
+                w.sort_handler.map(|h| h(w, e))
+            }
+            (Table(w), Event::RowSelected(e)) if e.value < w.len() => {
+                w.select_handler.map(|h| h(w, e))
+            }
+            (Tabs(w), Event::Selected(e)) if w.contains(&e.path) => w.handler.map(|h| h(w, e)),
+            (Modal(w), Event::Dismissed(e)) if w.open => w.handler.map(|h| h(w, e)),
+            (Custom(w), e) => w.handle(e),
+            _ => None,
+        }
+    }
+
+    /// Routes `event` to the handler of the widget at `path`, if any.
+    ///
+    /// Events are refused by every descendant of a disabled widget, as well as by every widget
+    /// outside of the topmost open [`Modal`], if any.
+    pub fn dispatch<S: Into<usize>>(
+        &self,
+        path: impl TreePath<Segment = S>,
+        event: &Event,
+    ) -> Option<A> {
+        let path: Vec<usize> = path.segments().into_iter().map(Into::into).collect();
+
+        if self.modal().map_or(false, |m| !path.starts_with(&m)) {
+            return None;
+        }
+
+        path.into_iter()
+            .try_fold(self, |w, i| match w.is_disabled() {
+                false => w.child(i),
+                true => None,
+            })?
+            .handle(event)
+    }
+
+    /// The path of the topmost open [`Modal`], that is the last one in depth-first order, if any.
+    pub fn modal(&self) -> Option<Vec<usize>> {
+        let mut modal = None;
+        let mut stack = vec![(Vec::new(), self)];
+
+        while let Some((path, widget)) = stack.pop() {
+            let children = widget.indices().zip(widget.children()).rev();
+            stack.extend(children.map(|(i, w)| ([&path[..], &[i]].concat(), w)));
+
+            if let Widget::Modal(w) = widget.resolve() {
+                if w.open {
+                    modal = Some(path);
+                }
+            }
+        }
+
+        modal
+    }
+
+    /// Resolves [`Widget::Shared`] indirections down to the actual widget.
+    pub fn resolve(&self) -> &Self {
+        match self {
+            Widget::Shared(w) => w.resolve(),
+            w => w,
+        }
+    }
+
+    /// Whether `self` and `other` are known to be equal without inspecting their contents.
+    ///
+    /// This is the case when both are the same reference or [`Widget::Shared`] pointing to the
+    /// same allocation.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Widget::Shared(a), Widget::Shared(b)) => Arc::ptr_eq(a, b),
+            (a, b) => std::ptr::eq(a, b),
+        }
+    }
 }
 
 impl<'a, 'w, A> Kind<Widget<'a, A>> for Widget<'w, A> {}
 
+impl<'w, A> Eq for Widget<'w, A> {}
+
+impl<'w, A> PartialEq for Widget<'w, A> {
+    fn eq(&self, other: &Self) -> bool {
+        use Widget::*;
+        self.ptr_eq(other)
+            || match (self.resolve(), other.resolve()) {
+                (Row(a), Row(b)) => a == b,
+                (Column(a), Column(b)) => a == b,
+                (Scroll(a), Scroll(b)) => a == b,
+                (VirtualList(a), VirtualList(b)) => a == b,
+                (Table(a), Table(b)) => a == b,
+                (Grid(a), Grid(b)) => a == b,
+                (Stack(a), Stack(b)) => a == b,
+                (Tabs(a), Tabs(b)) => a == b,
+                (Modal(a), Modal(b)) => a == b,
+                (Button(a), Button(b)) => a == b,
+                (Entry(a), Entry(b)) => a == b,
+                (Checkbox(a), Checkbox(b)) => a == b,
+                (TreeView(a), TreeView(b)) => a == b,
+                (Text(a), Text(b)) => a == b,
+                (Stateful(a), Stateful(b)) => a == b,
+                (Custom(a), Custom(b)) => a == b,
+                _ => false,
+            }
+    }
+}
+
+use std::hash::{Hash, Hasher};
+
+impl<'w, A> Hash for Widget<'w, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let widget = self.resolve();
+        std::mem::discriminant(widget).hash(state);
+
+        use Widget::*;
+        match widget {
+            Row(w) => w.hash(state),
+            Column(w) => w.hash(state),
+            Scroll(w) => w.hash(state),
+            VirtualList(w) => w.hash(state),
+            Table(w) => w.hash(state),
+            Grid(w) => w.hash(state),
+            Stack(w) => w.hash(state),
+            Tabs(w) => w.hash(state),
+            Modal(w) => w.hash(state),
+            Button(w) => w.hash(state),
+            Entry(w) => w.hash(state),
+            Checkbox(w) => w.hash(state),
+            TreeView(w) => w.hash(state),
+            Text(w) => w.hash(state),
+            Stateful(w) => w.hash(state),
+            Custom(w) => w.hash(state),
+            Shared(_) => unreachable!(),
+        }
+    }
+}
+
 impl<'a: 'v, 'w: 'v, 'v, A> From<&'a Widget<'w, A>> for Widget<'v, A> {
     fn from(widget: &'a Widget<'w, A>) -> Self {
         use Widget::*;
         match widget {
             Row(w) => (&**w).into(),
             Column(w) => (&**w).into(),
+            Scroll(w) => (&**w).into(),
+            VirtualList(w) => (&**w).into(),
+            Table(w) => (&**w).into(),
+            Grid(w) => (&**w).into(),
+            Stack(w) => (&**w).into(),
+            Tabs(w) => (&**w).into(),
+            Modal(w) => (&**w).into(),
             Button(w) => (&**w).into(),
             Entry(w) => (&**w).into(),
             Checkbox(w) => (&**w).into(),
+            TreeView(w) => (&**w).into(),
+            Text(w) => (&**w).into(),
+            Stateful(w) => (&**w).into(),
+            Shared(w) => Shared(w.clone()),
+            Custom(w) => (&**w).into(),
         }
     }
 }
@@ -86,6 +356,90 @@ impl<'a: 'w, 'r: 'w, 'w, A> From<&'a Column<'r, A>> for Widget<'w, A> {
     }
 }
 
+impl<'s: 'w, 'w, A> From<Scroll<'s, A>> for Widget<'w, A> {
+    fn from(widget: Scroll<'s, A>) -> Self {
+        Widget::Scroll(widget.into())
+    }
+}
+
+impl<'a: 'w, 's: 'w, 'w, A> From<&'a Scroll<'s, A>> for Widget<'w, A> {
+    fn from(widget: &'a Scroll<'s, A>) -> Self {
+        Widget::Scroll(widget.into())
+    }
+}
+
+impl<'l: 'w, 'w, A> From<VirtualList<'l, A>> for Widget<'w, A> {
+    fn from(widget: VirtualList<'l, A>) -> Self {
+        Widget::VirtualList(widget.into())
+    }
+}
+
+impl<'a: 'w, 'l: 'w, 'w, A> From<&'a VirtualList<'l, A>> for Widget<'w, A> {
+    fn from(widget: &'a VirtualList<'l, A>) -> Self {
+        Widget::VirtualList(widget.into())
+    }
+}
+
+impl<'t: 'w, 'w, A> From<Table<'t, A>> for Widget<'w, A> {
+    fn from(widget: Table<'t, A>) -> Self {
+        Widget::Table(widget.into())
+    }
+}
+
+impl<'a: 'w, 't: 'w, 'w, A> From<&'a Table<'t, A>> for Widget<'w, A> {
+    fn from(widget: &'a Table<'t, A>) -> Self {
+        Widget::Table(widget.into())
+    }
+}
+
+impl<'g: 'w, 'w, A> From<Grid<'g, A>> for Widget<'w, A> {
+    fn from(widget: Grid<'g, A>) -> Self {
+        Widget::Grid(widget.into())
+    }
+}
+
+impl<'a: 'w, 'g: 'w, 'w, A> From<&'a Grid<'g, A>> for Widget<'w, A> {
+    fn from(widget: &'a Grid<'g, A>) -> Self {
+        Widget::Grid(widget.into())
+    }
+}
+
+impl<'s: 'w, 'w, A> From<Stack<'s, A>> for Widget<'w, A> {
+    fn from(widget: Stack<'s, A>) -> Self {
+        Widget::Stack(widget.into())
+    }
+}
+
+impl<'a: 'w, 's: 'w, 'w, A> From<&'a Stack<'s, A>> for Widget<'w, A> {
+    fn from(widget: &'a Stack<'s, A>) -> Self {
+        Widget::Stack(widget.into())
+    }
+}
+
+impl<'t: 'w, 'w, A> From<Tabs<'t, A>> for Widget<'w, A> {
+    fn from(widget: Tabs<'t, A>) -> Self {
+        Widget::Tabs(widget.into())
+    }
+}
+
+impl<'a: 'w, 't: 'w, 'w, A> From<&'a Tabs<'t, A>> for Widget<'w, A> {
+    fn from(widget: &'a Tabs<'t, A>) -> Self {
+        Widget::Tabs(widget.into())
+    }
+}
+
+impl<'m: 'w, 'w, A> From<Modal<'m, A>> for Widget<'w, A> {
+    fn from(widget: Modal<'m, A>) -> Self {
+        Widget::Modal(widget.into())
+    }
+}
+
+impl<'a: 'w, 'm: 'w, 'w, A> From<&'a Modal<'m, A>> for Widget<'w, A> {
+    fn from(widget: &'a Modal<'m, A>) -> Self {
+        Widget::Modal(widget.into())
+    }
+}
+
 impl<'w, A> From<Button<A>> for Widget<'w, A> {
     fn from(widget: Button<A>) -> Self {
         Widget::Button(widget.into())
@@ -122,6 +476,60 @@ impl<'a: 'w, 'w, A> From<&'a Checkbox<A>> for Widget<'w, A> {
     }
 }
 
+impl<'w, A> From<TreeView<A>> for Widget<'w, A> {
+    fn from(widget: TreeView<A>) -> Self {
+        Widget::TreeView(widget.into())
+    }
+}
+
+impl<'a: 'w, 'w, A> From<&'a TreeView<A>> for Widget<'w, A> {
+    fn from(widget: &'a TreeView<A>) -> Self {
+        Widget::TreeView(widget.into())
+    }
+}
+
+impl<'w, A> From<Text> for Widget<'w, A> {
+    fn from(widget: Text) -> Self {
+        Widget::Text(widget.into())
+    }
+}
+
+impl<'a: 'w, 'w, A> From<&'a Text> for Widget<'w, A> {
+    fn from(widget: &'a Text) -> Self {
+        Widget::Text(widget.into())
+    }
+}
+
+impl<'s: 'w, 'w, A> From<Stateful<'s, A>> for Widget<'w, A> {
+    fn from(widget: Stateful<'s, A>) -> Self {
+        Widget::Stateful(widget.into())
+    }
+}
+
+impl<'a: 'w, 's: 'w, 'w, A> From<&'a Stateful<'s, A>> for Widget<'w, A> {
+    fn from(widget: &'a Stateful<'s, A>) -> Self {
+        Widget::Stateful(widget.into())
+    }
+}
+
+impl<'c: 'w, 'w, A> From<Custom<'c, A>> for Widget<'w, A> {
+    fn from(widget: Custom<'c, A>) -> Self {
+        Widget::Custom(widget.into())
+    }
+}
+
+impl<'a: 'w, 'c: 'w, 'w, A> From<&'a Custom<'c, A>> for Widget<'w, A> {
+    fn from(widget: &'a Custom<'c, A>) -> Self {
+        Widget::Custom(widget.into())
+    }
+}
+
+impl<'s: 'w, 'w, A> From<Arc<Widget<'s, A>>> for Widget<'w, A> {
+    fn from(widget: Arc<Widget<'s, A>>) -> Self {
+        Widget::Shared(widget)
+    }
+}
+
 use std::slice::Iter;
 
 impl<'a, 'w: 'a, A> IntoIterator for &'a Widget<'w, A> {
@@ -133,6 +541,16 @@ impl<'a, 'w: 'a, A> IntoIterator for &'a Widget<'w, A> {
         match self {
             Row(w) => w.into_iter(),
             Column(w) => w.into_iter(),
+            Scroll(w) => w.into_iter(),
+            VirtualList(w) => w.into_iter(),
+            Table(w) => w.into_iter(),
+            Grid(w) => w.into_iter(),
+            Stack(w) => w.into_iter(),
+            Tabs(w) => w.into_iter(),
+            Modal(w) => w.into_iter(),
+            Stateful(w) => w.into_iter(),
+            Shared(w) => (&**w).into_iter(),
+            Custom(w) => w.into_iter(),
             _ => [].iter(),
         }
     }
@@ -206,11 +624,22 @@ impl<A: 'static + Default> Arbitrary for Widget<'static, A> {
             any::<Button<A>>().prop_map_into(),
             any::<Entry<A>>().prop_map_into(),
             any::<Checkbox<A>>().prop_map_into(),
+            any::<TreeView<A>>().prop_map_into(),
+            any::<Text>().prop_map_into(),
+            any::<VirtualList<A>>().prop_map_into(),
         ]
         .prop_recursive(d as u32, size as u32, b as u32, move |inner| {
             prop_oneof![
                 any_with::<Row<A>>(children(inner.clone(), 0..=b)).prop_map_into(),
                 any_with::<Column<A>>(children(inner.clone(), 0..=b)).prop_map_into(),
+                any_with::<Scroll<A>>(children(inner.clone(), 0..=b)).prop_map_into(),
+                any_with::<Table<A>>(children(inner.clone(), 0..=b)).prop_map_into(),
+                any_with::<Grid<A>>(children(inner.clone(), 0..=b)).prop_map_into(),
+                any_with::<Stack<A>>(children(inner.clone(), 0..=b)).prop_map_into(),
+                any_with::<Tabs<A>>(children(inner.clone(), 0..=b)).prop_map_into(),
+                any_with::<Modal<A>>(children(inner.clone(), 0..=b)).prop_map_into(),
+                any_with::<Stateful<A>>(children(inner.clone(), 0..=1)).prop_map_into(),
+                inner.prop_map(Arc::new).prop_map_into(),
             ]
         })
         .boxed()
@@ -220,14 +649,20 @@ impl<A: 'static + Default> Arbitrary for Widget<'static, A> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{event::*, widget, OptionalHandler};
     use maybe_owned::MaybeOwned::*;
     use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
     use std::iter::FromIterator;
 
     #[derive(Default)]
     struct Action;
 
+    #[test]
+    fn send_and_sync() {
+        fn assert<T: Send + Sync>() {}
+        assert::<Widget<Action>>();
+    }
+
     proptest! {
         #[test]
         fn get(root: Widget<Action>) {
@@ -236,12 +671,12 @@ mod tests {
             while let Some((p, w)) = indexed.pop() {
                 assert_eq!(root.get(p.iter().copied()), Some(w));
 
-                let out_of_bounds = [&p[..], &[w.into_iter().count()]].concat();
+                let out_of_bounds = [&p[..], &[w.indices().end]].concat();
                 assert_eq!(root.get(out_of_bounds), None);
 
                 indexed.extend(
-                    w.into_iter()
-                        .enumerate()
+                    w.indices()
+                        .zip(w)
                         .map(|(i, w)| ([&p[..], &[i]].concat(), w)),
                 );
             }
@@ -261,6 +696,55 @@ mod tests {
             assert_eq!(Widget::from(&Widget::from(w.clone())), Widget::Column(Borrowed(&w)));
         }
 
+        #[test]
+        fn from_scroll(w: Scroll<Action>) {
+            assert_eq!(Widget::from(&w), Widget::Scroll(Borrowed(&w)));
+            assert_eq!(Widget::from(w.clone()), Widget::Scroll(Owned(w.clone())));
+            assert_eq!(Widget::from(&Widget::from(w.clone())), Widget::Scroll(Borrowed(&w)));
+        }
+
+        #[test]
+        fn from_virtual_list(w: VirtualList<Action>) {
+            assert_eq!(Widget::from(&w), Widget::VirtualList(Borrowed(&w)));
+            assert_eq!(Widget::from(w.clone()), Widget::VirtualList(Owned(w.clone())));
+            assert_eq!(Widget::from(&Widget::from(w.clone())), Widget::VirtualList(Borrowed(&w)));
+        }
+
+        #[test]
+        fn from_table(w: Table<Action>) {
+            assert_eq!(Widget::from(&w), Widget::Table(Borrowed(&w)));
+            assert_eq!(Widget::from(w.clone()), Widget::Table(Owned(w.clone())));
+            assert_eq!(Widget::from(&Widget::from(w.clone())), Widget::Table(Borrowed(&w)));
+        }
+
+        #[test]
+        fn from_grid(w: Grid<Action>) {
+            assert_eq!(Widget::from(&w), Widget::Grid(Borrowed(&w)));
+            assert_eq!(Widget::from(w.clone()), Widget::Grid(Owned(w.clone())));
+            assert_eq!(Widget::from(&Widget::from(w.clone())), Widget::Grid(Borrowed(&w)));
+        }
+
+        #[test]
+        fn from_stack(w: Stack<Action>) {
+            assert_eq!(Widget::from(&w), Widget::Stack(Borrowed(&w)));
+            assert_eq!(Widget::from(w.clone()), Widget::Stack(Owned(w.clone())));
+            assert_eq!(Widget::from(&Widget::from(w.clone())), Widget::Stack(Borrowed(&w)));
+        }
+
+        #[test]
+        fn from_tabs(w: Tabs<Action>) {
+            assert_eq!(Widget::from(&w), Widget::Tabs(Borrowed(&w)));
+            assert_eq!(Widget::from(w.clone()), Widget::Tabs(Owned(w.clone())));
+            assert_eq!(Widget::from(&Widget::from(w.clone())), Widget::Tabs(Borrowed(&w)));
+        }
+
+        #[test]
+        fn from_modal(w: Modal<Action>) {
+            assert_eq!(Widget::from(&w), Widget::Modal(Borrowed(&w)));
+            assert_eq!(Widget::from(w.clone()), Widget::Modal(Owned(w.clone())));
+            assert_eq!(Widget::from(&Widget::from(w.clone())), Widget::Modal(Borrowed(&w)));
+        }
+
         #[test]
         fn from_button(w: Button<Action>) {
             assert_eq!(Widget::from(&w), Widget::Button(Borrowed(&w)));
@@ -282,6 +766,68 @@ mod tests {
             assert_eq!(Widget::from(&Widget::from(w.clone())), Widget::Checkbox(Borrowed(&w)));
         }
 
+        #[test]
+        fn from_tree_view(w: TreeView<Action>) {
+            assert_eq!(Widget::from(&w), Widget::TreeView(Borrowed(&w)));
+            assert_eq!(Widget::from(w.clone()), Widget::TreeView(Owned(w.clone())));
+            assert_eq!(Widget::from(&Widget::from(w.clone())), Widget::TreeView(Borrowed(&w)));
+        }
+
+        #[test]
+        fn from_text(w: Text) {
+            assert_eq!(Widget::<Action>::from(&w), Widget::Text(Borrowed(&w)));
+            assert_eq!(Widget::<Action>::from(w.clone()), Widget::Text(Owned(w.clone())));
+            assert_eq!(Widget::from(&Widget::<Action>::from(w.clone())), Widget::Text(Borrowed(&w)));
+        }
+
+        #[test]
+        fn from_stateful(w: Stateful<Action>) {
+            assert_eq!(Widget::from(&w), Widget::Stateful(Borrowed(&w)));
+            assert_eq!(Widget::from(w.clone()), Widget::Stateful(Owned(w.clone())));
+            assert_eq!(Widget::from(&Widget::from(w.clone())), Widget::Stateful(Borrowed(&w)));
+        }
+
+        #[test]
+        fn from_shared(w: Widget<Action>) {
+            let shared = Arc::new(w);
+            assert_eq!(Widget::from(shared.clone()), Widget::Shared(shared.clone()));
+
+            match Widget::from(&Widget::from(shared.clone())) {
+                Widget::Shared(s) => assert!(Arc::ptr_eq(&s, &shared)),
+                w => panic!("expected a shared widget, got {:?}", w),
+            }
+        }
+
+        #[test]
+        fn shared_is_transparent(w: Widget<Action>) {
+            let shared = Widget::from(Arc::new(w.clone()));
+            assert_eq!(shared, w);
+
+            let mut a = DefaultHasher::new();
+            shared.hash(&mut a);
+
+            let mut b = DefaultHasher::new();
+            w.hash(&mut b);
+
+            assert_eq!(a.finish(), b.finish());
+        }
+
+        #[test]
+        fn resolve(w: Widget<Action>) {
+            let shared = Widget::from(Arc::new(Widget::from(Arc::new(w.clone()))));
+            assert_eq!(shared.resolve(), w.resolve());
+            assert!(!matches!(shared.resolve(), Widget::Shared(_)));
+        }
+
+        #[test]
+        fn ptr_eq(w: Widget<Action>) {
+            let shared = Widget::from(Arc::new(w.clone()));
+            assert!(shared.ptr_eq(&shared.clone()));
+            assert!(!shared.ptr_eq(&Widget::from(Arc::new(w.clone()))));
+            assert!(w.ptr_eq(&w));
+            assert!(!w.resolve().ptr_eq(&w.resolve().clone()));
+        }
+
         #[test]
         fn clone(w: Widget<Action>) {
             assert_eq!(w.clone(), w);
@@ -303,9 +849,17 @@ mod tests {
             let items = Vec::from_iter(&w);
 
             use Widget::*;
-            match &w {
+            match w.resolve() {
                 Row(w) => assert_eq!(items, Vec::from_iter(&**w)),
                 Column(w) => assert_eq!(items, Vec::from_iter(&**w)),
+                Scroll(w) => assert_eq!(items, Vec::from_iter(&**w)),
+                VirtualList(w) => assert_eq!(items, Vec::from_iter(&**w)),
+                Table(w) => assert_eq!(items, Vec::from_iter(&**w)),
+                Grid(w) => assert_eq!(items, Vec::from_iter(&**w)),
+                Stack(w) => assert_eq!(items, Vec::from_iter(&**w)),
+                Tabs(w) => assert_eq!(items, Vec::from_iter(&**w)),
+                Modal(w) => assert_eq!(items, Vec::from_iter(&**w)),
+                Stateful(w) => assert_eq!(items, Vec::from_iter(&**w)),
                 _ => assert_eq!(items, Vec::<&Widget<_>>::new())
             }
         }
@@ -313,20 +867,117 @@ mod tests {
         #[test]
         fn children(w: Widget<Action>) {
             use Widget::*;
-            match &w {
+            match w.resolve() {
                 Row(r) => assert_eq!(w.children(), &***r),
                 Column(c) => assert_eq!(w.children(), &***c),
+                Scroll(s) => assert_eq!(w.children(), &***s),
+                VirtualList(l) => assert_eq!(w.children(), &***l),
+                Table(t) => assert_eq!(w.children(), &***t),
+                Grid(g) => assert_eq!(w.children(), &***g),
+                Stack(s) => assert_eq!(w.children(), &***s),
+                Tabs(t) => assert_eq!(w.children(), &***t),
+                Modal(m) => assert_eq!(w.children(), &***m),
+                Stateful(s) => assert_eq!(w.children(), &***s),
                 _ => assert_eq!(w.children(), &[])
             }
         }
 
+        #[test]
+        fn handle(w: Widget<Action>, e: Event) {
+            use Widget::*;
+            let handled = match (w.resolve(), &e) {
+                (Button(w), Event::Clicked(_)) => matches!(w.handler, OptionalHandler::Some(_)) && !w.disabled,
+                (Entry(w), Event::Entered(_)) => matches!(w.handler, OptionalHandler::Some(_)) && !w.disabled && !w.readonly,
+                (Checkbox(w), Event::Toggled(_)) => matches!(w.handler, OptionalHandler::Some(_)) && !w.disabled,
+                (TreeView(w), Event::Expanded(e)) => matches!(w.expand_handler, OptionalHandler::Some(_)) && !w.disabled && w.get(e.path.iter().copied()).is_some(),
+                (TreeView(w), Event::Collapsed(e)) => matches!(w.collapse_handler, OptionalHandler::Some(_)) && !w.disabled && w.get(e.path.iter().copied()).is_some(),
+                (TreeView(w), Event::Selected(e)) => matches!(w.select_handler, OptionalHandler::Some(_)) && !w.disabled && w.get(e.path.iter().copied()).is_some(),
+                (Scroll(w), Event::Scrolled(_)) => w.handler.is_some() && !w.disabled,
+                (VirtualList(w), Event::Scrolled(_)) => w.handler.is_some() && !w.disabled,
+                (Table(w), Event::Sorted(e)) => w.sort_handler.is_some() && !w.disabled && e.value.0 < w.columns().len(),
+                (Table(w), Event::RowSelected(e)) => w.select_handler.is_some() && !w.disabled && e.value < w.len(),
+                (Tabs(w), Event::Selected(e)) => w.handler.is_some() && !w.disabled && e.path.len() == 1 && e.path[0] < w.pages().len(),
+                (Modal(w), Event::Dismissed(_)) => w.handler.is_some() && !w.disabled && w.open,
+                _ => false,
+            };
+
+            assert_eq!(w.handle(&e).is_some(), handled);
+        }
+
+        #[test]
+        fn tables_refuse_events_out_of_range(table: Table<'static, Action>, column in 0..8usize, row in 0..8usize, order: Order) {
+            let handled = |e: Event| Widget::from(&table).handle(&e).is_some();
+            let enabled = !table.disabled;
+
+            let sorted = handled(Sorted { value: (column, order) }.into());
+            assert_eq!(sorted, enabled && table.sort_handler.is_some() && column < table.columns().len());
+
+            let selected = handled(RowSelected { value: row }.into());
+            assert_eq!(selected, enabled && table.select_handler.is_some() && row < table.len());
+        }
+
+        #[test]
+        fn dispatch(root: Widget<Action>, e: Event) {
+            let modal = root.modal();
+            let mut indices = vec![(Vec::<usize>::new(), false)];
+
+            while let Some((p, inherited)) = indices.pop() {
+                let w = &root[p.iter().copied()];
+                let disabled = inherited || w.is_disabled();
+                let trapped = modal.as_ref().map_or(false, |m| !p.starts_with(m));
+                indices.extend(w.indices().map(|i| ([&p[..], &[i]].concat(), disabled)));
+                assert_eq!(root.dispatch(p.iter().copied(), &e).is_some(), w.handle(&e).is_some() && !inherited && !trapped);
+
+                let out_of_bounds = [&p[..], &[w.indices().end]].concat();
+                assert!(root.dispatch(out_of_bounds, &e).is_none());
+            }
+        }
+
+        #[test]
+        fn disabled_containers_lock_their_descendants(e: Event) {
+            let clicked: fn(&Button<Action>, &Clicked) -> Action = |_, _| Action;
+            let entered: fn(&Entry<Action>, &Entered) -> Action = |_, _| Action;
+            let toggled: fn(&Checkbox<Action>, &Toggled) -> Action = |_, _| Action;
+
+            let form = |disabled| -> Widget<Action> {
+                widget!(Column [
+                    Row { disabled } [
+                        Button { handler: clicked }
+                        Entry { handler: entered }
+                        Checkbox { handler: toggled }
+                    ]
+                    Entry { handler: entered, readonly: true }
+                ])
+            };
+
+            for i in 0..3usize {
+                assert_eq!(form(false).dispatch(vec![0, i], &e).is_some(), form(false)[[0, i]].handle(&e).is_some());
+                assert!(form(true).dispatch(vec![0, i], &e).is_none());
+            }
+
+            assert!(form(false).dispatch(vec![1usize], &e).is_none());
+        }
+
+        #[test]
+        fn modal_is_the_last_open_one(w: Widget<Action>, title: String) {
+            let open = |children: Vec<Widget<'static, Action>>| Widget::from(Modal { title: title.clone(), children: children.into(), open: true, ..Default::default() });
+            let within = |i: usize| w.modal().map(|m| [&[i][..], &m].concat());
+
+            assert_eq!(Widget::from(Row { children: Box::new([w.clone(), open(vec![])]), ..Default::default() }).modal(), Some(vec![1]));
+            assert_eq!(open(vec![w.clone()]).modal(), within(0).or(Some(vec![])));
+            assert_eq!(open(vec![w.clone(), open(vec![])]).modal(), Some(vec![1]));
+
+            let closed = Widget::from(Modal { children: Box::new([w.clone()]), ..Default::default() });
+            assert_eq!(Widget::from(Column { children: Box::new([closed, w.clone()]), ..Default::default() }).modal(), within(1));
+        }
+
         #[test]
         fn index(root: Widget<Action>) {
             let mut indices = vec![Vec::<usize>::new()];
 
             while let Some(p) = indices.pop() {
                 let w = &root[p.iter().copied()];
-                indices.extend((0..w.into_iter().count()).map(|i| [&p[..], &[i]].concat()));
+                indices.extend(w.indices().map(|i| [&p[..], &[i]].concat()));
                 assert_eq!(Some(w), root.get(p));
             }
         }
@@ -334,7 +985,7 @@ mod tests {
         #[test]
         #[should_panic]
         fn index_out_of_bounds(w: Widget<Action>) {
-            let _ = &w[vec![w.into_iter().count()]];
+            let _ = &w[vec![w.indices().end]];
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::{event::Clicked, widget::Widget, OptionalHandler, Variant};
+use crate::{event::Clicked, style::Style, widget::Widget, OptionalHandler, Variant};
 
 /// The semantic representation of a button.
 #[derive(derivative::Derivative)]
@@ -13,6 +13,9 @@ use crate::{event::Clicked, widget::Widget, OptionalHandler, Variant};
 pub struct Button<A> {
     pub label: String,
     pub handler: OptionalHandler<Button<A>, Clicked, A>,
+    pub style: Style,
+    /// Whether clicks are refused.
+    pub disabled: bool,
 }
 
 impl<'w, A> Variant<Widget<'w, A>> for Button<A> {}
@@ -26,8 +29,18 @@ impl<A: 'static + Default> Arbitrary for Button<A> {
     type Strategy = BoxedStrategy<Self>;
 
     fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
-        (any::<String>(), any::<OptionalHandler<_, _, _>>())
-            .prop_map(|(label, handler)| Button { label, handler })
+        (
+            any::<String>(),
+            any::<OptionalHandler<_, _, _>>(),
+            any::<Style>(),
+            any::<bool>(),
+        )
+            .prop_map(|(label, handler, style, disabled)| Button {
+                label,
+                handler,
+                style,
+                disabled,
+            })
             .boxed()
     }
 }
@@ -48,6 +61,8 @@ mod tests {
             Button {
                 label: "".into(),
                 handler: OptionalHandler::None,
+                style: Style::new(),
+                disabled: false,
             }
         );
     }
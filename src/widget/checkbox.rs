@@ -1,4 +1,4 @@
-use crate::{event::Toggled, widget::Widget, OptionalHandler, Variant};
+use crate::{event::Toggled, style::Style, widget::Widget, Lens, OptionalHandler, Set, Variant};
 
 /// The semantic representation of a checkbox.
 #[derive(derivative::Derivative)]
@@ -14,10 +14,27 @@ pub struct Checkbox<A> {
     pub label: String,
     pub value: bool,
     pub handler: OptionalHandler<Checkbox<A>, Toggled, A>,
+    pub style: Style,
+    /// Whether toggles are refused.
+    pub disabled: bool,
 }
 
 impl<'w, A> Variant<Widget<'w, A>> for Checkbox<A> {}
 
+impl<A> Checkbox<A> {
+    /// A checkbox bound to the flag `lens` focuses on in `state`, which produces [`Set`] actions.
+    pub fn bound<S: 'static>(lens: &'static Lens<S, bool>, state: &S) -> Self
+    where
+        A: From<Set<S>>,
+    {
+        Checkbox {
+            value: *lens.get(state),
+            handler: lens.into(),
+            ..Default::default()
+        }
+    }
+}
+
 #[cfg(test)]
 use proptest::{arbitrary::Arbitrary, prelude::*};
 
@@ -31,11 +48,15 @@ impl<A: 'static + Default> Arbitrary for Checkbox<A> {
             any::<String>(),
             any::<bool>(),
             any::<OptionalHandler<_, _, _>>(),
+            any::<Style>(),
+            any::<bool>(),
         )
-            .prop_map(|(label, value, handler)| Checkbox {
+            .prop_map(|(label, value, handler, style, disabled)| Checkbox {
                 label,
                 value,
                 handler,
+                style,
+                disabled,
             })
             .boxed()
     }
@@ -58,6 +79,8 @@ mod tests {
                 value: false,
                 label: "".into(),
                 handler: OptionalHandler::None,
+                style: Style::new(),
+                disabled: false,
             }
         );
     }
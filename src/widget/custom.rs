@@ -0,0 +1,304 @@
+use crate::{event::Event, widget::Widget, Variant};
+use std::any::{Any, TypeId};
+use std::fmt::{self, Debug};
+use std::hash::{Hash, Hasher};
+
+/// The interface of widgets defined outside of this crate.
+///
+/// Custom widgets are mounted into a widget tree through [`Custom`], which also holds their
+/// children, and are typically implemented through `#[derive(Widget)]`.
+/// Components nested within custom widgets are not mounted by the [`Runtime`](crate::Runtime).
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::{event::Clicked, widget, OptionalHandler, Widget};
+///
+/// #[derive(Debug, Default, Clone, Widget)]
+/// #[widget(action = u32)]
+/// struct Stepper {
+///     value: u32,
+///     #[widget(handler)]
+///     handler: OptionalHandler<Stepper, Clicked, u32>,
+/// }
+///
+/// let handler: fn(&Stepper, &Clicked) -> u32 = |s, _| s.value + 1;
+/// let stepper: Widget<u32> = widget!(Stepper { value: 41u32, handler } [ Button ]);
+///
+/// assert_eq!(stepper.handle(&Clicked::default().into()), Some(42));
+/// assert_eq!(stepper.children(), &[widget!(Button)]);
+/// ```
+pub trait CustomWidget<A>: 'static + Send + Sync + Debug + Clone + Eq + Hash {
+    /// The name of the widget.
+    fn name(&self) -> &'static str;
+
+    /// Routes `event` to the handler of this widget, if it handles events of that kind.
+    fn handle(&self, _event: &Event) -> Option<A> {
+        None
+    }
+}
+
+trait ErasedWidget<A>: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn dyn_eq(&self, other: &dyn ErasedWidget<A>) -> bool;
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+    fn dyn_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    fn dyn_clone(&self) -> Box<dyn ErasedWidget<A>>;
+    fn name(&self) -> &'static str;
+    fn handle(&self, event: &Event) -> Option<A>;
+}
+
+impl<A, W: CustomWidget<A>> ErasedWidget<A> for W {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn ErasedWidget<A>) -> bool {
+        other.as_any().downcast_ref::<W>() == Some(self)
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        self.hash(&mut state)
+    }
+
+    fn dyn_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ErasedWidget<A>> {
+        Box::new(self.clone())
+    }
+
+    fn name(&self) -> &'static str {
+        CustomWidget::name(self)
+    }
+
+    fn handle(&self, event: &Event) -> Option<A> {
+        CustomWidget::handle(self, event)
+    }
+}
+
+/// The semantic representation of a [`CustomWidget`] along with its children.
+pub struct Custom<'w, A> {
+    widget: Box<dyn ErasedWidget<A>>,
+    pub children: Box<[Widget<'w, A>]>,
+}
+
+impl<'w, A> Custom<'w, A> {
+    /// Erases the type of `widget`.
+    pub fn new<W: CustomWidget<A>>(widget: W) -> Self {
+        Custom {
+            widget: Box::new(widget),
+            children: Box::new([]),
+        }
+    }
+
+    /// Replaces the children.
+    pub fn with_children(mut self, children: impl Into<Box<[Widget<'w, A>]>>) -> Self {
+        self.children = children.into();
+        self
+    }
+
+    /// The [`TypeId`] of the concrete widget.
+    pub fn type_id(&self) -> TypeId {
+        self.widget.as_any().type_id()
+    }
+
+    /// Returns a reference to the concrete widget, if it is of type `W`.
+    pub fn downcast_ref<W: CustomWidget<A>>(&self) -> Option<&W> {
+        self.widget.as_any().downcast_ref()
+    }
+
+    /// The name of the widget.
+    pub fn name(&self) -> &'static str {
+        self.widget.name()
+    }
+
+    /// Routes `event` to the handler of this widget, if it handles events of that kind.
+    pub fn handle(&self, event: &Event) -> Option<A> {
+        self.widget.handle(event)
+    }
+}
+
+impl<'w, A> Variant<Widget<'w, A>> for Custom<'w, A> {}
+
+impl<'w, A> Clone for Custom<'w, A> {
+    fn clone(&self) -> Self {
+        Custom {
+            widget: self.widget.dyn_clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+struct Erased<'a, A>(&'a dyn ErasedWidget<A>);
+
+impl<'a, A> Debug for Erased<'a, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.dyn_fmt(f)
+    }
+}
+
+impl<'w, A> Debug for Custom<'w, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Custom")
+            .field("widget", &Erased(&*self.widget))
+            .field("children", &self.children)
+            .finish()
+    }
+}
+
+impl<'w, A> Eq for Custom<'w, A> {}
+
+impl<'w, A> PartialEq for Custom<'w, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.widget.dyn_eq(&*other.widget) && self.children == other.children
+    }
+}
+
+impl<'w, A> Hash for Custom<'w, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.type_id().hash(state);
+        self.widget.dyn_hash(state);
+        self.children.hash(state);
+    }
+}
+
+use std::slice::Iter;
+
+impl<'a, 'w: 'a, A> IntoIterator for &'a Custom<'w, A> {
+    type Item = &'a Widget<'w, A>;
+    type IntoIter = Iter<'a, Widget<'w, A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.children.iter()
+    }
+}
+
+use std::ops::Deref;
+
+impl<'w, A> Deref for Custom<'w, A> {
+    type Target = [Widget<'w, A>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.children
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::*;
+    use crate::{widget, OptionalHandler};
+    use proptest::prelude::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    enum Action {
+        Stepped(u32),
+        Renamed(String),
+    }
+
+    #[derive(derivative::Derivative, crate::Widget)]
+    #[derivative(Debug(bound = ""), Default(bound = ""), Clone(bound = ""))]
+    struct Stepper<A> {
+        value: u32,
+        #[widget(handler)]
+        handler: OptionalHandler<Stepper<A>, Clicked, A>,
+        #[widget(handler)]
+        renamed: OptionalHandler<Stepper<A>, Entered, A>,
+    }
+
+    #[derive(Debug, Default, Clone, crate::Widget)]
+    #[widget(action = Action)]
+    struct Label(String);
+
+    fn hash<T: Hash>(t: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn send_and_sync() {
+        fn assert<T: Send + Sync>() {}
+        assert::<Custom<Action>>();
+    }
+
+    proptest! {
+        #[test]
+        fn derive_implements_eq_and_hash(a: u32, b: u32) {
+            let (x, y) = (Stepper::<Action> { value: a, ..Default::default() }, Stepper { value: b, ..Default::default() });
+            assert_eq!(x == y, a == b);
+            assert_eq!(hash(&x) == hash(&y), a == b);
+        }
+
+        #[test]
+        fn erasure_preserves_eq_and_hash(a: String, b: String) {
+            let (x, y) = (Custom::new(Label(a.clone())), Custom::new(Label(b.clone())));
+            assert_eq!(x == y, a == b);
+            assert_eq!(hash(&x) == hash(&y), a == b);
+            assert_eq!(x.clone(), x);
+
+            let z = x.clone().with_children(vec![Widget::from(Label(a))]);
+            assert_ne!(x, z);
+            assert_eq!(z.clone(), z);
+            assert_eq!(z.deref(), &*z.children);
+            assert_eq!(z.into_iter().collect::<Vec<_>>(), z.children.iter().collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn different_types_are_never_equal(s: String) {
+            let stepper = Custom::new(Stepper::<Action>::default());
+            let label = Custom::new(Label(s));
+            assert_ne!(stepper.type_id(), label.type_id());
+            assert!(stepper != label);
+        }
+
+        #[test]
+        fn downcast_ref(s: String) {
+            let label = Custom::<Action>::new(Label(s.clone()));
+            assert_eq!(label.downcast_ref::<Label>().map(|l| &l.0), Some(&s));
+            assert!(label.downcast_ref::<Stepper<Action>>().is_none());
+            assert_eq!(label.name(), "Label");
+            assert_eq!(format!("{:?}", label), format!("Custom {{ widget: {:?}, children: [] }}", Label(s)));
+        }
+
+        #[test]
+        fn widget_macro_integration(value: u32, label: String) {
+            let handler: fn(&Stepper<Action>, &Clicked) -> Action = |s, _| Action::Stepped(s.value);
+            let renamed: fn(&Stepper<Action>, &Entered) -> Action = |_, e| Action::Renamed(e.value.clone());
+
+            let tree = widget!(Column [
+                Stepper { value, handler, renamed } [
+                    Label
+                    Button
+                ]
+            ]);
+
+            let custom = match &tree[vec![0usize]] {
+                Widget::Custom(c) => c.clone(),
+                w => panic!("expected a custom widget, got {:?}", w),
+            };
+
+            assert_eq!(custom.downcast_ref::<Stepper<Action>>().map(|s| s.value), Some(value));
+            assert_eq!(&*custom.children, &[Widget::from(Label::default()), widget!(Button)]);
+            assert_eq!(tree.get(vec![0usize, 1]), Some(&widget!(Button)));
+
+            let clicked = Clicked::default().into();
+            assert_eq!(tree.dispatch(vec![0usize], &clicked), Some(Action::Stepped(value)));
+
+            let entered = Entered { value: label.clone() }.into();
+            assert_eq!(tree.dispatch(vec![0usize], &entered), Some(Action::Renamed(label)));
+
+            let toggled = Toggled::default().into();
+            assert_eq!(tree.dispatch(vec![0usize], &toggled), None);
+            assert_eq!(tree.dispatch(vec![0usize, 0], &clicked), None);
+        }
+
+        #[test]
+        fn from_borrowed(s: String) {
+            let label = Label(s);
+            assert_eq!(Widget::from(&label), Widget::from(label.clone()));
+        }
+    }
+}
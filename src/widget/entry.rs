@@ -1,4 +1,4 @@
-use crate::{event::Entered, widget::Widget, OptionalHandler, Variant};
+use crate::{event::Entered, style::Style, widget::Widget, Lens, OptionalHandler, Set, Variant};
 
 /// The semantic representation of text input.
 #[derive(derivative::Derivative)]
@@ -13,10 +13,29 @@ use crate::{event::Entered, widget::Widget, OptionalHandler, Variant};
 pub struct Entry<A> {
     pub value: String,
     pub handler: OptionalHandler<Entry<A>, Entered, A>,
+    pub style: Style,
+    /// Whether input is refused.
+    pub disabled: bool,
+    /// Whether input is refused, while the value may still be selected and copied.
+    pub readonly: bool,
 }
 
 impl<'w, A> Variant<Widget<'w, A>> for Entry<A> {}
 
+impl<A> Entry<A> {
+    /// An entry bound to the text `lens` focuses on in `state`, which produces [`Set`] actions.
+    pub fn bound<S: 'static>(lens: &'static Lens<S, String>, state: &S) -> Self
+    where
+        A: From<Set<S>>,
+    {
+        Entry {
+            value: lens.get(state).clone(),
+            handler: lens.into(),
+            ..Default::default()
+        }
+    }
+}
+
 #[cfg(test)]
 use proptest::{arbitrary::Arbitrary, prelude::*};
 
@@ -26,8 +45,20 @@ impl<A: 'static + Default> Arbitrary for Entry<A> {
     type Strategy = BoxedStrategy<Self>;
 
     fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
-        (any::<String>(), any::<OptionalHandler<_, _, _>>())
-            .prop_map(|(value, handler)| Entry { value, handler })
+        (
+            any::<String>(),
+            any::<OptionalHandler<_, _, _>>(),
+            any::<Style>(),
+            any::<bool>(),
+            any::<bool>(),
+        )
+            .prop_map(|(value, handler, style, disabled, readonly)| Entry {
+                value,
+                handler,
+                style,
+                disabled,
+                readonly,
+            })
             .boxed()
     }
 }
@@ -48,6 +79,9 @@ mod tests {
             Entry {
                 value: "".into(),
                 handler: OptionalHandler::None,
+                style: Style::new(),
+                disabled: false,
+                readonly: false,
             }
         );
     }
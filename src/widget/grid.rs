@@ -0,0 +1,266 @@
+use crate::{style::Style, widget::Widget, Variant};
+
+/// The cells of a [`Grid`] a widget is placed in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Area {
+    pub row: usize,
+    pub column: usize,
+    /// The number of rows spanned, at least one.
+    pub rows: usize,
+    /// The number of columns spanned, at least one.
+    pub columns: usize,
+}
+
+impl Area {
+    /// The single cell at `row` and `column`.
+    pub fn new(row: usize, column: usize) -> Self {
+        Area {
+            row,
+            column,
+            rows: 1,
+            columns: 1,
+        }
+    }
+
+    /// Spans `rows` and `columns` from the same top left cell.
+    pub fn span(self, rows: usize, columns: usize) -> Self {
+        Area {
+            rows: rows.max(1),
+            columns: columns.max(1),
+            ..self
+        }
+    }
+
+    /// Whether this area covers the cell at `row` and `column`.
+    pub fn contains(&self, row: usize, column: usize) -> bool {
+        (self.row..self.row + self.rows).contains(&row)
+            && (self.column..self.column + self.columns).contains(&column)
+    }
+}
+
+impl Default for Area {
+    fn default() -> Self {
+        Area::new(0, 0)
+    }
+}
+
+/// The semantic representation of a container that places widgets in explicit cells of a grid.
+///
+/// Every child is placed in an [`Area`], which may span several rows and columns, while the
+/// size of the grid is that of the smallest one that covers every area.
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::widget::{Area, Button, Grid, Text};
+///
+/// let grid: Grid<()> = Grid::new()
+///     .place(Area::new(0, 0).span(1, 2), Text { content: "Title".into(), ..Default::default() })
+///     .place(Area::new(1, 0), Button::default())
+///     .place(Area::new(1, 1), Button::default());
+///
+/// assert_eq!(grid.size(), (2, 2));
+/// assert_eq!(grid.at(0, 1), grid.get(0));
+/// assert_eq!(grid.area(2), Some(Area::new(1, 1)));
+/// ```
+#[derive(derivative::Derivative)]
+#[derivative(
+    Debug(bound = ""),
+    Default(bound = ""),
+    Clone(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = ""),
+    Hash(bound = "")
+)]
+pub struct Grid<'w, A> {
+    pub(crate) children: Box<[Widget<'w, A>]>,
+    areas: Box<[Area]>,
+    pub style: Style,
+    /// Whether events are refused by every descendant.
+    pub disabled: bool,
+}
+
+impl<'w, A> Variant<Widget<'w, A>> for Grid<'w, A> {}
+
+impl<'w, A> Grid<'w, A> {
+    /// An empty grid.
+    pub fn new() -> Self {
+        Grid::default()
+    }
+
+    /// Appends `widget` as a child placed in `area`.
+    pub fn place(self, area: Area, widget: impl Into<Widget<'w, A>>) -> Self {
+        let (mut children, mut areas) = (self.children.into_vec(), self.areas.into_vec());
+        children.push(widget.into());
+        areas.push(area.span(area.rows, area.columns));
+
+        Grid {
+            children: children.into(),
+            areas: areas.into(),
+            ..self
+        }
+    }
+
+    /// The area the child at index `i` is placed in.
+    pub fn area(&self, i: usize) -> Option<Area> {
+        self.areas.get(i).copied()
+    }
+
+    pub fn areas(&self) -> &[Area] {
+        &self.areas
+    }
+
+    /// The number of rows and columns.
+    pub fn size(&self) -> (usize, usize) {
+        self.areas.iter().fold((0, 0), |(rows, columns), a| {
+            (rows.max(a.row + a.rows), columns.max(a.column + a.columns))
+        })
+    }
+
+    /// The first child placed over the cell at `row` and `column`, if any.
+    pub fn at(&self, row: usize, column: usize) -> Option<&Widget<'w, A>> {
+        let i = self.areas.iter().position(|a| a.contains(row, column))?;
+        self.children.get(i)
+    }
+
+    /// Compares everything but the children.
+    pub(crate) fn props(&self) -> impl Eq + '_ {
+        (&self.areas, &self.style, self.disabled)
+    }
+}
+
+use std::slice::Iter;
+
+impl<'a, 'w: 'a, A> IntoIterator for &'a Grid<'w, A> {
+    type Item = &'a Widget<'w, A>;
+    type IntoIter = Iter<'a, Widget<'w, A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.children.iter()
+    }
+}
+
+use std::ops::Deref;
+
+impl<'w, A> Deref for Grid<'w, A> {
+    type Target = [Widget<'w, A>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.children
+    }
+}
+
+#[cfg(test)]
+use super::ChildrenStrategy;
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, collection::vec, prelude::*};
+
+#[cfg(test)]
+impl Arbitrary for Area {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (0..8usize, 0..8usize, 1..=3usize, 1..=3usize)
+            .prop_map(|(row, column, rows, columns)| Area::new(row, column).span(rows, columns))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+impl<A: 'static + Default> Arbitrary for Grid<'static, A> {
+    type Parameters = ChildrenStrategy<A>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        (
+            params,
+            vec(any::<Area>(), 32),
+            any::<Style>(),
+            any::<bool>(),
+        )
+            .prop_map(|(children, areas, style, disabled)| Grid {
+                style,
+                disabled,
+                ..children
+                    .into_vec()
+                    .into_iter()
+                    .zip(areas)
+                    .fold(Grid::new(), |g, (w, a)| g.place(a, w))
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::Text;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    #[derive(Default)]
+    struct Action;
+
+    #[test]
+    fn default() {
+        let grid = Grid::<Action>::default();
+
+        assert!(grid.is_empty());
+        assert_eq!(grid.size(), (0, 0));
+        assert_eq!(grid.style, Style::new());
+        assert!(!grid.disabled);
+    }
+
+    #[test]
+    fn spans_cover_at_least_one_cell() {
+        assert_eq!(Area::new(2, 3).span(0, 0), Area::new(2, 3));
+
+        let grid = Grid::<Action>::new().place(
+            Area {
+                rows: 0,
+                ..Area::new(1, 1)
+            },
+            Text::default(),
+        );
+        assert_eq!(grid.area(0), Some(Area::new(1, 1)));
+        assert_eq!(grid.size(), (2, 2));
+    }
+
+    proptest! {
+        #[test]
+        fn clone(grid: Grid<'static, Action>) {
+            assert_eq!(grid.clone(), grid);
+        }
+
+        #[test]
+        fn hash(x: Grid<'static, Action>, y: Grid<'static, Action>) {
+            let mut a = DefaultHasher::new();
+            x.hash(&mut a);
+
+            let mut b = DefaultHasher::new();
+            y.hash(&mut b);
+
+            assert_eq!(x == y, a.finish() == b.finish());
+        }
+
+        #[test]
+        fn children_are_found_at_every_cell_of_their_area(grid: Grid<'static, Action>) {
+            let (rows, columns) = grid.size();
+            assert_eq!(grid.areas().len(), grid.len());
+
+            for (i, area) in grid.areas().iter().enumerate() {
+                assert!(area.row + area.rows <= rows && area.column + area.columns <= columns);
+
+                for (r, c) in (area.row..area.row + area.rows).flat_map(|r| (area.column..area.column + area.columns).map(move |c| (r, c))) {
+                    let first = grid.areas().iter().position(|a| a.contains(r, c));
+                    assert!(first <= Some(i));
+                    assert_eq!(grid.at(r, c), grid.get(first.unwrap()));
+                }
+            }
+
+            assert_eq!(grid.at(rows, 0), None);
+            assert_eq!(grid.at(0, columns), None);
+        }
+    }
+}
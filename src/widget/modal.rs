@@ -0,0 +1,191 @@
+use crate::{event::Dismissed, style::Style, widget::Widget, Variant};
+use std::hash::{Hash, Hasher};
+
+/// The semantic representation of a dialog, which backends draw above every other widget while
+/// it is `open`.
+///
+/// A modal may be placed anywhere in the tree, but only shows its children while open, during
+/// which focus is trapped inside: events dispatched to widgets outside of the topmost open modal
+/// are refused, see [`Widget::modal`].
+/// Backends report attempts to close the dialog, such as pressing escape, as [`Dismissed`]
+/// events, and applications close it by clearing `open`.
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::event::{Clicked, Dismissed};
+/// use oxidizer::widget::{Button, Modal};
+/// use oxidizer::{widget, Widget};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Action { Delete, Cancel }
+///
+/// let cancel: fn(&Modal<Action>, &Dismissed) -> Action = |_, _| Action::Cancel;
+/// let delete: fn(&Button<Action>, &Clicked) -> Action = |_, _| Action::Delete;
+///
+/// let ui: Widget<Action> = widget!(Column [
+///     Button { label: "Delete", handler: delete }
+///     Modal { title: "Delete this file?", open: true, handler: cancel } [
+///         Button { label: "Delete", handler: delete }
+///     ]
+/// ]);
+///
+/// assert_eq!(ui.modal(), Some(vec![1]));
+/// assert_eq!(ui.dispatch(vec![1usize, 0], &Clicked::default().into()), Some(Action::Delete));
+/// assert_eq!(ui.dispatch(vec![0usize], &Clicked::default().into()), None);
+/// assert_eq!(ui.dispatch(vec![1usize], &Dismissed.into()), Some(Action::Cancel));
+/// ```
+#[derive(derivative::Derivative)]
+#[derivative(Debug(bound = ""), Default(bound = ""), Clone(bound = ""))]
+pub struct Modal<'w, A> {
+    pub title: String,
+    pub children: Box<[Widget<'w, A>]>,
+    /// Whether the dialog is shown.
+    pub open: bool,
+    pub handler: Option<fn(&Modal<A>, &Dismissed) -> A>,
+    pub style: Style,
+    /// Whether events are refused by every descendant.
+    pub disabled: bool,
+}
+
+impl<'w, A> Variant<Widget<'w, A>> for Modal<'w, A> {}
+
+impl<'w, A> Modal<'w, A> {
+    /// Compares everything but the children shown.
+    pub(crate) fn props(&self) -> impl Eq + Hash + '_ {
+        let hidden: &[_] = match self.open {
+            true => &[],
+            false => &self.children,
+        };
+
+        (
+            &self.title,
+            self.open,
+            hidden,
+            self.handler.map(|h| h as *const ()),
+            &self.style,
+            self.disabled,
+        )
+    }
+}
+
+impl<'w, A> Eq for Modal<'w, A> {}
+
+impl<'w, A> PartialEq for Modal<'w, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.props() == other.props() && self.children == other.children
+    }
+}
+
+impl<'w, A> Hash for Modal<'w, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.props().hash(state);
+        self.children.hash(state);
+    }
+}
+
+use std::slice::Iter;
+
+impl<'a, 'w: 'a, A> IntoIterator for &'a Modal<'w, A> {
+    type Item = &'a Widget<'w, A>;
+    type IntoIter = Iter<'a, Widget<'w, A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+use std::ops::Deref;
+
+impl<'w, A> Deref for Modal<'w, A> {
+    type Target = [Widget<'w, A>];
+
+    fn deref(&self) -> &Self::Target {
+        match self.open {
+            true => &self.children,
+            false => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+use super::ChildrenStrategy;
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, prelude::*};
+
+#[cfg(test)]
+impl<A: 'static + Default> Arbitrary for Modal<'static, A> {
+    type Parameters = ChildrenStrategy<A>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        let handler: fn(&Modal<A>, &Dismissed) -> A = |_, _| A::default();
+
+        (
+            any::<String>(),
+            params,
+            any::<bool>(),
+            any::<bool>(),
+            any::<Style>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                move |(title, children, open, handled, style, disabled)| Modal {
+                    title,
+                    children,
+                    open,
+                    handler: handled.then_some(handler),
+                    style,
+                    disabled,
+                },
+            )
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    #[derive(Default)]
+    struct Action;
+
+    #[test]
+    fn default() {
+        let modal = Modal::<Action>::default();
+
+        assert_eq!(modal.title, "");
+        assert!(modal.children.is_empty());
+        assert!(!modal.open);
+        assert!(modal.handler.is_none());
+        assert_eq!(modal.style, Style::new());
+        assert!(!modal.disabled);
+    }
+
+    proptest! {
+        #[test]
+        fn clone(modal: Modal<'static, Action>) {
+            assert_eq!(modal.clone(), modal);
+        }
+
+        #[test]
+        fn hash(x: Modal<'static, Action>, y: Modal<'static, Action>) {
+            let mut a = DefaultHasher::new();
+            x.hash(&mut a);
+
+            let mut b = DefaultHasher::new();
+            y.hash(&mut b);
+
+            assert_eq!(x == y, a.finish() == b.finish());
+        }
+
+        #[test]
+        fn children_are_only_shown_while_open(modal: Modal<'static, Action>) {
+            match modal.open {
+                true => assert_eq!(&*modal, &*modal.children),
+                false => assert!(modal.is_empty()),
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{widget::Widget, Variant};
+use crate::{style::Style, widget::Widget, Variant};
 
 /// The semantic representation of a container that displays widgets horizontally.
 #[derive(derivative::Derivative)]
@@ -12,6 +12,9 @@ use crate::{widget::Widget, Variant};
 )]
 pub struct Row<'w, A> {
     pub children: Box<[Widget<'w, A>]>,
+    pub style: Style,
+    /// Whether events are refused by every descendant.
+    pub disabled: bool,
 }
 
 impl<'w, A> Variant<Widget<'w, A>> for Row<'w, A> {}
@@ -33,7 +36,7 @@ impl<'w, A> Deref for Row<'w, A> {
     type Target = [Widget<'w, A>];
 
     fn deref(&self) -> &Self::Target {
-        &*self.children
+        &self.children
     }
 }
 
@@ -49,7 +52,13 @@ impl<A: 'static + Default> Arbitrary for Row<'static, A> {
     type Strategy = BoxedStrategy<Self>;
 
     fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
-        params.prop_map(|children| Row { children }).boxed()
+        (params, any::<Style>(), any::<bool>())
+            .prop_map(|(children, style, disabled)| Row {
+                children,
+                style,
+                disabled,
+            })
+            .boxed()
     }
 }
 
@@ -67,7 +76,9 @@ mod tests {
         assert_eq!(
             Row::<Action>::default(),
             Row {
-                children: Default::default()
+                children: Default::default(),
+                style: Style::new(),
+                disabled: false,
             }
         );
     }
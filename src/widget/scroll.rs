@@ -0,0 +1,256 @@
+use crate::{event::Scrolled, style::Style, widget::Widget, TreePath, Variant};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+/// The semantic representation of a container that displays widgets vertically within a
+/// viewport, which may be scrolled.
+///
+/// Applications restore the scroll position across rebuilds by keeping `offset` in sync with
+/// [`Scrolled`] events, while backends report the size of the viewport back through `viewport`
+/// such that [`Scroll::visible`] knows which children need to be displayed.
+///
+/// Unlike other widgets, the handler is a plain function pointer, which may inspect the scroll
+/// regardless of the lifetime of its children.
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::widget::{Button, Scroll, Widget};
+///
+/// let items: Vec<Widget<()>> = (0..100).map(|i| Button { label: i.to_string(), ..Default::default() }.into()).collect();
+///
+/// let scroll = Scroll {
+///     children: items.into(),
+///     viewport: (200, 100),
+///     extent: 20,
+///     ..Default::default()
+/// }
+/// .scroll_to(vec![42usize]);
+///
+/// assert_eq!(scroll.offset, (0, 840));
+/// assert_eq!(scroll.visible(), 42..47);
+/// ```
+#[derive(derivative::Derivative)]
+#[derivative(
+    Debug(bound = ""),
+    Default(bound = ""),
+    Clone(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = ""),
+    Hash(bound = "")
+)]
+pub struct Scroll<'w, A> {
+    pub children: Box<[Widget<'w, A>]>,
+    /// The scroll position, horizontally and vertically, in logical pixels.
+    pub offset: (u32, u32),
+    /// The size of the visible area, horizontally and vertically, in logical pixels,
+    /// or zero if unknown.
+    pub viewport: (u32, u32),
+    /// The height of every child in logical pixels, or zero if unknown or not uniform.
+    pub extent: u32,
+    /// The path, relative to this widget, of a descendant backends should bring into view.
+    pub anchor: Option<Vec<usize>>,
+    #[derivative(
+        PartialEq(compare_with = "eq_handler"),
+        Hash(hash_with = "hash_handler")
+    )]
+    pub handler: Option<fn(&Scroll<A>, &Scrolled) -> A>,
+    pub style: Style,
+    /// Whether events are refused by every descendant.
+    pub disabled: bool,
+}
+
+impl<'w, A> Variant<Widget<'w, A>> for Scroll<'w, A> {}
+
+impl<'w, A> Scroll<'w, A> {
+    /// The range of children within the viewport, as estimated from `extent`.
+    ///
+    /// Every child is considered visible unless both `extent` and the height of the viewport
+    /// are known.
+    pub fn visible(&self) -> Range<usize> {
+        let (top, height) = (self.offset.1, self.viewport.1);
+        let len = self.children.len();
+
+        if self.extent == 0 || height == 0 {
+            return 0..len;
+        }
+
+        let start = (top / self.extent) as usize;
+        let bottom = top.saturating_add(height);
+        let end = (bottom / self.extent + u32::from(bottom % self.extent != 0)) as usize;
+        start.min(len)..end.min(len)
+    }
+
+    /// Brings the descendant at `path`, relative to this widget, into view.
+    ///
+    /// The path is recorded as the `anchor` for backends to honor, while `offset` is estimated
+    /// from `extent`, if known, such that the child that contains the descendant is at the top
+    /// of the viewport.
+    pub fn scroll_to<S: Into<usize>>(mut self, path: impl TreePath<Segment = S>) -> Self {
+        let path: Vec<usize> = path.segments().into_iter().map(Into::into).collect();
+
+        if let Some(&i) = path.first() {
+            if self.extent > 0 && i < self.children.len() {
+                self.offset.1 = i as u32 * self.extent;
+            }
+        }
+
+        self.anchor = Some(path);
+        self
+    }
+
+    /// Compares everything but the children.
+    pub(crate) fn props(&self) -> impl Eq + '_ {
+        (
+            self.offset,
+            self.viewport,
+            self.extent,
+            &self.anchor,
+            decay(&self.handler),
+            &self.style,
+            self.disabled,
+        )
+    }
+}
+
+type ScrollHandler<A> = Option<fn(&Scroll<A>, &Scrolled) -> A>;
+
+fn decay<A>(handler: &ScrollHandler<A>) -> Option<*const ()> {
+    handler.map(|f| f as *const ())
+}
+
+fn eq_handler<A>(a: &ScrollHandler<A>, b: &ScrollHandler<A>) -> bool {
+    decay(a) == decay(b)
+}
+
+fn hash_handler<A, H: Hasher>(handler: &ScrollHandler<A>, state: &mut H) {
+    decay(handler).hash(state);
+}
+
+use std::slice::Iter;
+
+impl<'a, 'w: 'a, A> IntoIterator for &'a Scroll<'w, A> {
+    type Item = &'a Widget<'w, A>;
+    type IntoIter = Iter<'a, Widget<'w, A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.children.iter()
+    }
+}
+
+use std::ops::Deref;
+
+impl<'w, A> Deref for Scroll<'w, A> {
+    type Target = [Widget<'w, A>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.children
+    }
+}
+
+#[cfg(test)]
+use super::ChildrenStrategy;
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, prelude::*, strategy::Strategy};
+
+#[cfg(test)]
+impl<A: 'static + Default> Arbitrary for Scroll<'static, A> {
+    type Parameters = ChildrenStrategy<A>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        (
+            params,
+            any::<(u16, u16)>(),
+            any::<(u16, u16)>(),
+            0..=32u32,
+            any::<bool>(),
+            any::<Style>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                |(children, offset, viewport, extent, handler, style, disabled)| Scroll {
+                    children,
+                    offset: (offset.0.into(), offset.1.into()),
+                    viewport: (viewport.0.into(), viewport.1.into()),
+                    extent,
+                    anchor: None,
+                    handler: handler
+                        .then_some((|_, _| A::default()) as fn(&Scroll<A>, &Scrolled) -> A),
+                    style,
+                    disabled,
+                },
+            )
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    #[derive(Default)]
+    struct Action;
+
+    #[test]
+    fn default() {
+        assert_eq!(
+            Scroll::<Action>::default(),
+            Scroll {
+                children: Default::default(),
+                offset: (0, 0),
+                viewport: (0, 0),
+                extent: 0,
+                anchor: None,
+                handler: None,
+                style: Style::new(),
+                disabled: false,
+            }
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone(scroll: Scroll<'static, Action>) {
+            assert_eq!(scroll.clone(), scroll);
+        }
+
+        #[test]
+        fn hash(x: Scroll<'static, Action>, y: Scroll<'static, Action>) {
+            let mut a = DefaultHasher::new();
+            x.hash(&mut a);
+
+            let mut b = DefaultHasher::new();
+            y.hash(&mut b);
+
+            assert_eq!(x == y, a.finish() == b.finish());
+        }
+
+        #[test]
+        fn visible_children_overlap_the_viewport(scroll: Scroll<'static, Action>) {
+            let visible = scroll.visible();
+            let (top, bottom) = (scroll.offset.1, scroll.offset.1 + scroll.viewport.1);
+
+            for i in 0..scroll.len() as u32 {
+                let overlaps = i * scroll.extent < bottom && (i + 1) * scroll.extent > top;
+                let unknown = scroll.extent == 0 || scroll.viewport.1 == 0;
+                assert_eq!(visible.contains(&(i as usize)), overlaps || unknown);
+            }
+        }
+
+        #[test]
+        fn scrolled_children_are_visible(scroll: Scroll<'static, Action>, path: Vec<usize>) {
+            let scroll = Scroll { viewport: (0, scroll.extent.max(1)), ..scroll };
+            let target = path.first().copied();
+            let scrolled = scroll.clone().scroll_to(path.clone());
+
+            assert_eq!(scrolled.anchor, Some(path));
+
+            match target.filter(|&i| i < scroll.len() && scroll.extent > 0) {
+                Some(i) => assert!(scrolled.visible().contains(&i)),
+                None => assert_eq!(scrolled.offset, scroll.offset),
+            }
+        }
+    }
+}
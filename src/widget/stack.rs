@@ -0,0 +1,157 @@
+use crate::{style::Style, widget::Widget, Variant};
+use std::hash::Hash;
+use std::ops::Range;
+
+/// The semantic representation of a container that only shows the child at `active`, like a
+/// stack of pages of which only the top one is visible.
+///
+/// Every child is kept, such that switching pages doesn't rebuild the rest of the tree, but only
+/// the active one is among [`Widget::children`].
+/// Paths address children by their index among every page, see [`Widget::indices`].
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::{widget, widget::Stack, Widget};
+///
+/// let pages: Widget<()> = widget!(Stack { active: 1usize } [
+///     Text { content: "General" }
+///     Text { content: "Advanced" }
+/// ]);
+///
+/// assert_eq!(pages.indices(), 1..2);
+/// assert_eq!(pages[[1usize]], widget!(Text { content: "Advanced" }));
+/// assert_eq!(pages.get([0usize]), None);
+/// ```
+#[derive(derivative::Derivative)]
+#[derivative(
+    Debug(bound = ""),
+    Default(bound = ""),
+    Clone(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = ""),
+    Hash(bound = "")
+)]
+pub struct Stack<'w, A> {
+    pub children: Box<[Widget<'w, A>]>,
+    /// The index of the child shown, which shows none if out of bounds.
+    pub active: usize,
+    pub style: Style,
+    /// Whether events are refused by every descendant.
+    pub disabled: bool,
+}
+
+impl<'w, A> Variant<Widget<'w, A>> for Stack<'w, A> {}
+
+impl<'w, A> Stack<'w, A> {
+    /// The range of indices of the child shown, which is empty if `active` is out of bounds.
+    pub fn visible(&self) -> Range<usize> {
+        match self.active < self.children.len() {
+            true => self.active..self.active + 1,
+            false => self.active..self.active,
+        }
+    }
+
+    /// Compares everything but the child shown.
+    pub(crate) fn props(&self) -> impl Eq + Hash + '_ {
+        let len = self.children.len();
+        let Range { start, end } = self.visible();
+        let hidden = (
+            &self.children[..start.min(len)],
+            &self.children[end.min(len)..],
+        );
+        (hidden, self.active, &self.style, self.disabled)
+    }
+}
+
+use std::slice::Iter;
+
+impl<'a, 'w: 'a, A> IntoIterator for &'a Stack<'w, A> {
+    type Item = &'a Widget<'w, A>;
+    type IntoIter = Iter<'a, Widget<'w, A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+use std::ops::Deref;
+
+impl<'w, A> Deref for Stack<'w, A> {
+    type Target = [Widget<'w, A>];
+
+    fn deref(&self) -> &Self::Target {
+        self.children.get(self.visible()).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+use super::ChildrenStrategy;
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, prelude::*};
+
+#[cfg(test)]
+impl<A: 'static + Default> Arbitrary for Stack<'static, A> {
+    type Parameters = ChildrenStrategy<A>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        (params, 0..4usize, any::<Style>(), any::<bool>())
+            .prop_map(|(children, active, style, disabled)| Stack {
+                children,
+                active,
+                style,
+                disabled,
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    #[derive(Default)]
+    struct Action;
+
+    #[test]
+    fn default() {
+        let stack = Stack::<Action>::default();
+
+        assert!(stack.children.is_empty());
+        assert_eq!(stack.active, 0);
+        assert_eq!(stack.visible(), 0..0);
+        assert_eq!(stack.style, Style::new());
+        assert!(!stack.disabled);
+    }
+
+    proptest! {
+        #[test]
+        fn clone(stack: Stack<'static, Action>) {
+            assert_eq!(stack.clone(), stack);
+        }
+
+        #[test]
+        fn hash(x: Stack<'static, Action>, y: Stack<'static, Action>) {
+            let mut a = DefaultHasher::new();
+            x.hash(&mut a);
+
+            let mut b = DefaultHasher::new();
+            y.hash(&mut b);
+
+            assert_eq!(x == y, a.finish() == b.finish());
+        }
+
+        #[test]
+        fn only_the_active_child_is_shown(stack: Stack<'static, Action>) {
+            match stack.children.get(stack.active) {
+                Some(child) => assert_eq!(&*stack, std::slice::from_ref(child)),
+                None => assert!(stack.is_empty()),
+            }
+
+            assert_eq!(stack.visible().start, stack.active);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{widget::Widget, AnyComponent, Variant};
+
+/// The semantic representation of a [`Component`](crate::Component) mounted into a widget tree.
+///
+/// The [`Runtime`](crate::Runtime) renders the component with its local state as the only child.
+/// Sibling components with the same non-empty `key` share their local state regardless of their
+/// position, otherwise state is tied to the position of the component within its parent.
+#[derive(derivative::Derivative)]
+#[derivative(
+    Debug(bound = ""),
+    Default(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = ""),
+    Hash(bound = "")
+)]
+pub struct Stateful<'w, A> {
+    pub key: String,
+    pub component: AnyComponent<A>,
+    pub children: Box<[Widget<'w, A>]>,
+}
+
+impl<'w, A> Variant<Widget<'w, A>> for Stateful<'w, A> {}
+
+impl<'w, A> Clone for Stateful<'w, A> {
+    fn clone(&self) -> Self {
+        Stateful {
+            key: self.key.clone(),
+            component: self.component.duplicate(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+use std::slice::Iter;
+
+impl<'a, 'w: 'a, A> IntoIterator for &'a Stateful<'w, A> {
+    type Item = &'a Widget<'w, A>;
+    type IntoIter = Iter<'a, Widget<'w, A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.children.iter()
+    }
+}
+
+use std::ops::Deref;
+
+impl<'w, A> Deref for Stateful<'w, A> {
+    type Target = [Widget<'w, A>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.children
+    }
+}
+
+#[cfg(test)]
+use super::ChildrenStrategy;
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, prelude::*, strategy::Strategy};
+
+#[cfg(test)]
+impl<A: 'static + Default> Arbitrary for Stateful<'static, A> {
+    type Parameters = ChildrenStrategy<A>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        (any::<String>(), params)
+            .prop_map(|(key, children)| Stateful {
+                key,
+                component: AnyComponent::default(),
+                children,
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    #[derive(Default)]
+    struct Action;
+
+    #[test]
+    fn default() {
+        assert_eq!(
+            Stateful::<Action>::default(),
+            Stateful {
+                key: "".into(),
+                component: AnyComponent::default(),
+                children: Default::default()
+            }
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone(stateful: Stateful<Action>) {
+            assert_eq!(stateful.clone(), stateful);
+        }
+
+        #[test]
+        fn hash(x: Stateful<Action>, y: Stateful<Action>) {
+            let mut a = DefaultHasher::new();
+            x.hash(&mut a);
+
+            let mut b = DefaultHasher::new();
+            y.hash(&mut b);
+
+            assert_eq!(x == y, a.finish() == b.finish());
+        }
+
+        #[test]
+        fn into_iter(stateful: Stateful<Action>) {
+            let items = stateful.into_iter().cloned().collect::<Box<[_]>>();
+            assert_eq!(items, stateful.children)
+        }
+
+        #[test]
+        fn deref(stateful: Stateful<Action>) {
+            assert_eq!(stateful.deref(), &*stateful.children)
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use crate::event::{Changed, Order, RowSelected, Sorted};
+use crate::{style::Style, widget::Text, widget::Widget, Variant};
+use std::hash::{Hash, Hasher};
+use std::slice::Chunks;
+
+/// The definition of a column of a [`Table`].
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TableColumn {
+    pub header: String,
+    /// The preferred width in logical pixels, or zero to share the remaining space.
+    pub width: u32,
+}
+
+impl TableColumn {
+    pub fn new(header: impl Into<String>) -> Self {
+        TableColumn {
+            header: header.into(),
+            width: 0,
+        }
+    }
+
+    pub fn width(self, width: u32) -> Self {
+        TableColumn { width, ..self }
+    }
+}
+
+/// The semantic representation of rows of cells aligned in columns under a header.
+///
+/// Cells are the children of the table in row-major order, such that the cell at `column` within
+/// `row` is addressed by [`Table::position`].
+/// Tables don't reorder their rows themselves: backends report clicks on headers as [`Sorted`]
+/// events, through [`Table::sort_by`], and applications rebuild the rows in the requested order.
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::event::{Order, Sorted};
+/// use oxidizer::widget::{Table, TableColumn, Text, Widget};
+///
+/// let cell = |s: &str| Widget::from(Text { content: s.into(), ..Default::default() });
+/// let handler: fn(&Table<String>, &Sorted) -> String = |t, e| t.columns()[e.value.0].header.clone();
+///
+/// let mut table = Table::new([TableColumn::new("Name"), TableColumn::new("Size").width(80)])
+///     .row([cell("a.txt"), cell("12")])
+///     .row([cell("b.txt")]);
+///
+/// table.sort_handler = Some(handler);
+///
+/// assert_eq!(table.len(), 2);
+/// assert_eq!(table.cell(1, 1), Some(&cell("")));
+/// assert_eq!(table.sort_by(1).value, (1, Order::Ascending));
+/// assert_eq!(Widget::from(&table).handle(&table.sort_by(1).into()), Some("Size".into()));
+/// ```
+#[derive(derivative::Derivative)]
+#[derivative(Debug(bound = ""), Default(bound = ""), Clone(bound = ""))]
+pub struct Table<'w, A> {
+    columns: Box<[TableColumn]>,
+    pub(crate) cells: Box<[Widget<'w, A>]>,
+    /// The column the rows are sorted by and in which direction, if any.
+    pub sort: Option<(usize, Order)>,
+    /// The index of the selected row, if any.
+    pub selected: Option<usize>,
+    pub sort_handler: Option<fn(&Table<A>, &Sorted) -> A>,
+    pub select_handler: Option<fn(&Table<A>, &RowSelected) -> A>,
+    pub style: Style,
+    /// Whether events are refused by the table and every cell.
+    pub disabled: bool,
+}
+
+impl<'w, A> Variant<Widget<'w, A>> for Table<'w, A> {}
+
+impl<'w, A> Table<'w, A> {
+    /// A table without rows.
+    pub fn new(columns: impl IntoIterator<Item = TableColumn>) -> Self {
+        Table {
+            columns: columns.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Appends a row of cells, one per column.
+    ///
+    /// Extra cells are dropped and missing ones are filled with empty [`Text`].
+    pub fn row<W: Into<Widget<'w, A>>>(self, cells: impl IntoIterator<Item = W>) -> Self {
+        let width = self.columns.len();
+        let mut row: Vec<_> = cells.into_iter().take(width).map(Into::into).collect();
+        row.resize_with(width, || Text::default().into());
+
+        let mut all = self.cells.into_vec();
+        all.extend(row);
+
+        Table {
+            cells: all.into(),
+            ..self
+        }
+    }
+
+    pub fn columns(&self) -> &[TableColumn] {
+        &self.columns
+    }
+
+    /// The cells of every row.
+    pub fn rows(&self) -> Chunks<'_, Widget<'w, A>> {
+        self.cells.chunks(self.columns.len().max(1))
+    }
+
+    /// The number of rows.
+    pub fn len(&self) -> usize {
+        self.rows().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The index of the cell at `column` within `row` among the children of this table.
+    pub fn position(&self, row: usize, column: usize) -> usize {
+        row * self.columns.len() + column
+    }
+
+    pub fn cell(&self, row: usize, column: usize) -> Option<&Widget<'w, A>> {
+        match column < self.columns.len() {
+            true => self.cells.get(self.position(row, column)),
+            false => None,
+        }
+    }
+
+    /// The event that sorts this table by `column`, in ascending order unless it is already.
+    pub fn sort_by(&self, column: usize) -> Sorted {
+        let order = match self.sort {
+            Some((c, order)) if c == column => order.reverse(),
+            _ => Order::Ascending,
+        };
+
+        Changed {
+            value: (column, order),
+        }
+    }
+
+    /// Compares everything but the cells.
+    pub(crate) fn props(&self) -> impl Eq + Hash + '_ {
+        (
+            &self.columns,
+            self.sort,
+            self.selected,
+            self.sort_handler.map(|h| h as *const ()),
+            self.select_handler.map(|h| h as *const ()),
+            &self.style,
+            self.disabled,
+        )
+    }
+}
+
+impl<'w, A> Eq for Table<'w, A> {}
+
+impl<'w, A> PartialEq for Table<'w, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.props() == other.props() && self.cells == other.cells
+    }
+}
+
+impl<'w, A> Hash for Table<'w, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.props().hash(state);
+        self.cells.hash(state);
+    }
+}
+
+use std::slice::Iter;
+
+impl<'a, 'w: 'a, A> IntoIterator for &'a Table<'w, A> {
+    type Item = &'a Widget<'w, A>;
+    type IntoIter = Iter<'a, Widget<'w, A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.iter()
+    }
+}
+
+use std::ops::Deref;
+
+impl<'w, A> Deref for Table<'w, A> {
+    type Target = [Widget<'w, A>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.cells
+    }
+}
+
+#[cfg(test)]
+use super::ChildrenStrategy;
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, collection::vec, prelude::*};
+
+#[cfg(test)]
+impl Arbitrary for TableColumn {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(), any::<u16>())
+            .prop_map(|(header, width)| TableColumn::new(header).width(width.into()))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+impl<A: 'static + Default> Arbitrary for Table<'static, A> {
+    type Parameters = ChildrenStrategy<A>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        let on_sort: fn(&Table<A>, &Sorted) -> A = |_, _| A::default();
+        let on_select: fn(&Table<A>, &RowSelected) -> A = |_, _| A::default();
+
+        (
+            vec(any::<TableColumn>(), 1..=3),
+            params,
+            any::<Option<(usize, Order)>>(),
+            any::<Option<usize>>(),
+            any::<(bool, bool)>(),
+            any::<Style>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                move |(columns, cells, sort, selected, handlers, style, disabled)| {
+                    let width = columns.len();
+                    let rows = cells.into_vec();
+                    let rows = rows.chunks(width).map(|r| r.to_vec());
+
+                    Table {
+                        sort,
+                        selected,
+                        sort_handler: handlers.0.then_some(on_sort),
+                        select_handler: handlers.1.then_some(on_select),
+                        style,
+                        disabled,
+                        ..rows.fold(Table::new(columns), Table::row)
+                    }
+                },
+            )
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::Button;
+    use std::collections::hash_map::DefaultHasher;
+
+    #[derive(Default)]
+    struct Action;
+
+    #[test]
+    fn default() {
+        let table = Table::<Action>::default();
+
+        assert!(table.is_empty());
+        assert_eq!(table.columns(), &[]);
+        assert_eq!(table.sort, None);
+        assert_eq!(table.selected, None);
+        assert!(table.sort_handler.is_none());
+        assert!(table.select_handler.is_none());
+        assert_eq!(table.style, Style::new());
+        assert!(!table.disabled);
+    }
+
+    proptest! {
+        #[test]
+        fn clone(table: Table<'static, Action>) {
+            assert_eq!(table.clone(), table);
+        }
+
+        #[test]
+        fn hash(x: Table<'static, Action>, y: Table<'static, Action>) {
+            let mut a = DefaultHasher::new();
+            x.hash(&mut a);
+
+            let mut b = DefaultHasher::new();
+            y.hash(&mut b);
+
+            assert_eq!(x == y, a.finish() == b.finish());
+        }
+
+        #[test]
+        fn rows_are_aligned_to_columns(columns in vec(any::<TableColumn>(), 0..4), rows in vec(vec(any::<String>(), 0..6), 0..8)) {
+            let button = |label: &String| Widget::<Action>::from(Button { label: label.clone(), ..Default::default() });
+            let table = rows.iter().fold(Table::new(columns.clone()), |t, r| t.row(r.iter().map(button)));
+
+            assert_eq!(table.len(), if columns.is_empty() { 0 } else { rows.len() });
+
+            for (i, row) in table.rows().enumerate() {
+                assert_eq!(row.len(), columns.len());
+
+                for (j, cell) in row.iter().enumerate() {
+                    let expected = rows[i].get(j).map_or_else(|| Text::default().into(), button);
+                    assert_eq!(cell, &expected);
+                    assert_eq!(table.cell(i, j), Some(cell));
+                    assert_eq!(&table[table.position(i, j)], cell);
+                }
+
+                assert_eq!(table.cell(i, columns.len()), None);
+            }
+        }
+
+        #[test]
+        fn sorting_by_the_same_column_reverses_the_order(table: Table<'static, Action>, column: usize) {
+            let sorted = Table { sort: Some(table.sort_by(column).value), ..table.clone() };
+
+            match table.sort {
+                Some((c, order)) if c == column => assert_eq!(sorted.sort, Some((column, order.reverse()))),
+                _ => assert_eq!(sorted.sort, Some((column, Order::Ascending))),
+            }
+
+            assert_eq!(sorted.sort_by(column).value, (column, sorted.sort.unwrap().1.reverse()));
+        }
+    }
+}
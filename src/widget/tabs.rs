@@ -0,0 +1,235 @@
+use crate::{event::Selected, style::Style, widget::Widget, Variant};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+/// The semantic representation of labeled pages of which only the one at `active` is shown,
+/// below a row of tabs to switch between them.
+///
+/// Backends report clicks on tabs as [`Selected`] events with the path `[i]` of the page, through
+/// [`Tabs::select`], and applications switch pages by setting `active`.
+/// Like [`Stack`](super::Stack), every page is kept but only the active one is among
+/// [`Widget::children`].
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::event::Selected;
+/// use oxidizer::widget::{Tabs, Text, Widget};
+///
+/// let page = |s: &str| Widget::from(Text { content: s.into(), ..Default::default() });
+/// let handler: fn(&Tabs<String>, &Selected) -> String = |t, e| t.labels()[e.path[0]].clone();
+///
+/// let mut tabs = Tabs::new().tab("General", page("name")).tab("Advanced", page("proxy"));
+/// tabs.handler = Some(handler);
+///
+/// assert_eq!(&*tabs, &[page("name")]);
+/// assert_eq!(tabs.select(1), Some(Selected { path: vec![1] }));
+/// assert_eq!(Widget::from(&tabs).handle(&tabs.select(1).unwrap().into()), Some("Advanced".into()));
+/// ```
+#[derive(derivative::Derivative)]
+#[derivative(Debug(bound = ""), Default(bound = ""), Clone(bound = ""))]
+pub struct Tabs<'w, A> {
+    labels: Box<[String]>,
+    pub(crate) pages: Box<[Widget<'w, A>]>,
+    /// The index of the page shown, which shows none if out of bounds.
+    pub active: usize,
+    pub handler: Option<fn(&Tabs<A>, &Selected) -> A>,
+    pub style: Style,
+    /// Whether events are refused by the tabs and every page.
+    pub disabled: bool,
+}
+
+impl<'w, A> Variant<Widget<'w, A>> for Tabs<'w, A> {}
+
+impl<'w, A> Tabs<'w, A> {
+    /// Tabs without pages.
+    pub fn new() -> Self {
+        Tabs::default()
+    }
+
+    /// Appends a page under a tab labeled `label`.
+    pub fn tab(self, label: impl Into<String>, page: impl Into<Widget<'w, A>>) -> Self {
+        let (mut labels, mut pages) = (self.labels.into_vec(), self.pages.into_vec());
+        labels.push(label.into());
+        pages.push(page.into());
+
+        Tabs {
+            labels: labels.into(),
+            pages: pages.into(),
+            ..self
+        }
+    }
+
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Every page, whether shown or not.
+    pub fn pages(&self) -> &[Widget<'w, A>] {
+        &self.pages
+    }
+
+    /// The range of indices of the page shown, which is empty if `active` is out of bounds.
+    pub fn visible(&self) -> Range<usize> {
+        match self.active < self.pages.len() {
+            true => self.active..self.active + 1,
+            false => self.active..self.active,
+        }
+    }
+
+    /// The event that selects the tab at index `i`, if there is such a tab.
+    pub fn select(&self, i: usize) -> Option<Selected> {
+        (i < self.pages.len()).then(|| Selected { path: vec![i] })
+    }
+
+    /// Whether an event targets an existing tab.
+    pub(crate) fn contains(&self, path: &[usize]) -> bool {
+        matches!(path, [i] if *i < self.pages.len())
+    }
+
+    /// Compares everything but the page shown.
+    pub(crate) fn props(&self) -> impl Eq + Hash + '_ {
+        let len = self.pages.len();
+        let Range { start, end } = self.visible();
+        let hidden = (&self.pages[..start.min(len)], &self.pages[end.min(len)..]);
+
+        (
+            &self.labels,
+            hidden,
+            self.active,
+            self.handler.map(|h| h as *const ()),
+            &self.style,
+            self.disabled,
+        )
+    }
+}
+
+impl<'w, A> Eq for Tabs<'w, A> {}
+
+impl<'w, A> PartialEq for Tabs<'w, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.props() == other.props() && **self == **other
+    }
+}
+
+impl<'w, A> Hash for Tabs<'w, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.props().hash(state);
+        (**self).hash(state);
+    }
+}
+
+use std::slice::Iter;
+
+impl<'a, 'w: 'a, A> IntoIterator for &'a Tabs<'w, A> {
+    type Item = &'a Widget<'w, A>;
+    type IntoIter = Iter<'a, Widget<'w, A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+use std::ops::Deref;
+
+impl<'w, A> Deref for Tabs<'w, A> {
+    type Target = [Widget<'w, A>];
+
+    fn deref(&self) -> &Self::Target {
+        self.pages.get(self.visible()).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+use super::ChildrenStrategy;
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, collection::vec, prelude::*};
+
+#[cfg(test)]
+impl<A: 'static + Default> Arbitrary for Tabs<'static, A> {
+    type Parameters = ChildrenStrategy<A>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        let handler: fn(&Tabs<A>, &Selected) -> A = |_, _| A::default();
+
+        (
+            params,
+            vec(any::<String>(), 8),
+            0..4usize,
+            any::<bool>(),
+            any::<Style>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                move |(pages, labels, active, handled, style, disabled)| Tabs {
+                    active,
+                    handler: handled.then_some(handler),
+                    style,
+                    disabled,
+                    ..pages
+                        .into_vec()
+                        .into_iter()
+                        .zip(labels)
+                        .fold(Tabs::new(), |t, (p, l)| t.tab(l, p))
+                },
+            )
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    #[derive(Default)]
+    struct Action;
+
+    #[test]
+    fn default() {
+        let tabs = Tabs::<Action>::default();
+
+        assert!(tabs.pages().is_empty());
+        assert_eq!(tabs.labels(), &[] as &[String]);
+        assert_eq!(tabs.active, 0);
+        assert!(tabs.handler.is_none());
+        assert_eq!(tabs.style, Style::new());
+        assert!(!tabs.disabled);
+    }
+
+    proptest! {
+        #[test]
+        fn clone(tabs: Tabs<'static, Action>) {
+            assert_eq!(tabs.clone(), tabs);
+        }
+
+        #[test]
+        fn hash(x: Tabs<'static, Action>, y: Tabs<'static, Action>) {
+            let mut a = DefaultHasher::new();
+            x.hash(&mut a);
+
+            let mut b = DefaultHasher::new();
+            y.hash(&mut b);
+
+            assert_eq!(x == y, a.finish() == b.finish());
+        }
+
+        #[test]
+        fn only_the_active_page_is_shown(tabs: Tabs<'static, Action>) {
+            assert_eq!(tabs.labels().len(), tabs.pages().len());
+
+            match tabs.pages().get(tabs.active) {
+                Some(page) => assert_eq!(&*tabs, std::slice::from_ref(page)),
+                None => assert!(tabs.is_empty()),
+            }
+        }
+
+        #[test]
+        fn only_existing_tabs_can_be_selected(tabs: Tabs<'static, Action>, i in 0..8usize) {
+            let selected = tabs.select(i);
+            assert_eq!(selected.is_some(), i < tabs.pages().len());
+            assert_eq!(selected.is_some(), tabs.contains(&[i]));
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{style::Style, widget::Widget, Variant};
+
+/// The semantic representation of a piece of text that doesn't handle events.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Text {
+    pub content: String,
+    pub style: Style,
+}
+
+impl<'w, A> Variant<Widget<'w, A>> for Text {}
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, prelude::*};
+
+#[cfg(test)]
+impl Arbitrary for Text {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(), any::<Style>())
+            .prop_map(|(content, style)| Text { content, style })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    #[test]
+    fn default() {
+        assert_eq!(
+            Text::default(),
+            Text {
+                content: "".into(),
+                style: Style::new(),
+            }
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone(text: Text) {
+            assert_eq!(text.clone(), text);
+        }
+
+        #[test]
+        fn hash(x: Text, y: Text) {
+            let mut a = DefaultHasher::new();
+            x.hash(&mut a);
+
+            let mut b = DefaultHasher::new();
+            y.hash(&mut b);
+
+            assert_eq!(x == y, a.finish() == b.finish());
+        }
+    }
+}
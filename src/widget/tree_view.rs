@@ -0,0 +1,270 @@
+use crate::event::{Collapsed, Event, Expanded, Selected};
+use crate::{style::Style, widget::Widget, OptionalHandler, TreePath, Variant};
+
+/// A node of a [`TreeView`].
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TreeNode {
+    pub label: String,
+    /// Whether the children are shown.
+    pub expanded: bool,
+    pub children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    /// A collapsed node without children.
+    pub fn new(label: impl Into<String>) -> Self {
+        TreeNode {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Appends `child` to the children of this node.
+    pub fn with(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+}
+
+/// The semantic representation of hierarchical data as a tree of labeled nodes, which may be
+/// expanded to show their children.
+///
+/// Nodes are addressed by their path, as with widgets, that is `[0, 2]` is the third child of the
+/// first node at the top level.
+/// [`Expanded`], [`Collapsed`] and [`Selected`] events carry the path of their target node and
+/// are refused unless the node exists.
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::event::Expanded;
+/// use oxidizer::widget::{TreeNode, TreeView, Widget};
+///
+/// let handler: fn(&TreeView<Vec<usize>>, &Expanded) -> Vec<usize> = |_, e| e.path.clone();
+///
+/// let tree = TreeView {
+///     nodes: vec![TreeNode::new("src").with(TreeNode::new("lib.rs")).with(TreeNode::new("main.rs"))],
+///     expand_handler: handler.into(),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(tree.visible(), vec![vec![0]]);
+/// assert_eq!(tree.toggle([0usize]), Some(Expanded { path: vec![0] }.into()));
+/// assert_eq!(Widget::from(&tree).handle(&tree.toggle([0usize]).unwrap()), Some(vec![0]));
+/// ```
+#[derive(derivative::Derivative)]
+#[derivative(
+    Debug(bound = ""),
+    Default(bound = ""),
+    Clone(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = ""),
+    Hash(bound = "")
+)]
+pub struct TreeView<A> {
+    /// The nodes at the top level.
+    pub nodes: Vec<TreeNode>,
+    /// The path of the selected node, if any.
+    pub selected: Option<Vec<usize>>,
+    pub expand_handler: OptionalHandler<TreeView<A>, Expanded, A>,
+    pub collapse_handler: OptionalHandler<TreeView<A>, Collapsed, A>,
+    pub select_handler: OptionalHandler<TreeView<A>, Selected, A>,
+    pub style: Style,
+    /// Whether events are refused.
+    pub disabled: bool,
+}
+
+impl<'w, A> Variant<Widget<'w, A>> for TreeView<A> {}
+
+impl<A> TreeView<A> {
+    /// The node at `path`, if any.
+    pub fn get<S: Into<usize>>(&self, path: impl TreePath<Segment = S>) -> Option<&TreeNode> {
+        let mut segments = path.segments().into_iter().map(Into::into);
+        let first = self.nodes.get(segments.next()?)?;
+        segments.try_fold(first, |n, i| n.children.get(i))
+    }
+
+    /// The node at `path`, if any.
+    pub fn get_mut<S: Into<usize>>(
+        &mut self,
+        path: impl TreePath<Segment = S>,
+    ) -> Option<&mut TreeNode> {
+        let mut segments = path.segments().into_iter().map(Into::into);
+        let first = self.nodes.get_mut(segments.next()?)?;
+        segments.try_fold(first, |n, i| n.children.get_mut(i))
+    }
+
+    /// The paths of the nodes shown, that is whose ancestors are all expanded, in depth-first
+    /// order.
+    pub fn visible(&self) -> Vec<Vec<usize>> {
+        let mut paths = Vec::new();
+        let mut stack: Vec<_> = (0..self.nodes.len()).rev().map(|i| vec![i]).collect();
+
+        while let Some(path) = stack.pop() {
+            let node = self.get(path.iter().copied()).expect("path is valid");
+
+            if node.expanded {
+                let children = (0..node.children.len()).rev();
+                stack.extend(children.map(|i| [&path[..], &[i]].concat()));
+            }
+
+            paths.push(path);
+        }
+
+        paths
+    }
+
+    /// The event that toggles the node at `path`, which collapses it if expanded and expands it
+    /// otherwise, if there is such a node.
+    pub fn toggle<S: Into<usize>>(
+        &self,
+        path: impl TreePath<Segment = S>,
+    ) -> Option<Event<'static>> {
+        let path: Vec<usize> = path.segments().into_iter().map(Into::into).collect();
+
+        match self.get(path.iter().copied())?.expanded {
+            true => Some(Collapsed { path }.into()),
+            false => Some(Expanded { path }.into()),
+        }
+    }
+
+    /// Whether an event targets an existing node.
+    pub(crate) fn contains(&self, path: &[usize]) -> bool {
+        self.get(path.iter().copied()).is_some()
+    }
+}
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, collection::vec, prelude::*};
+
+#[cfg(test)]
+impl Arbitrary for TreeNode {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let leaf = (any::<String>(), any::<bool>()).prop_map(|(label, expanded)| TreeNode {
+            label,
+            expanded,
+            children: Vec::new(),
+        });
+
+        leaf.prop_recursive(3, 16, 3, |inner| {
+            (any::<String>(), any::<bool>(), vec(inner, 0..=3)).prop_map(
+                |(label, expanded, children)| TreeNode {
+                    label,
+                    expanded,
+                    children,
+                },
+            )
+        })
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+impl<A: 'static + Default> Arbitrary for TreeView<A> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            vec(any::<TreeNode>(), 0..=3),
+            any::<Option<Vec<usize>>>(),
+            any::<OptionalHandler<_, _, _>>(),
+            any::<OptionalHandler<_, _, _>>(),
+            any::<OptionalHandler<_, _, _>>(),
+            any::<Style>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                |(nodes, selected, expand, collapse, select, style, disabled)| TreeView {
+                    nodes,
+                    selected,
+                    expand_handler: expand,
+                    collapse_handler: collapse,
+                    select_handler: select,
+                    style,
+                    disabled,
+                },
+            )
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    #[derive(Default)]
+    struct Action;
+
+    #[test]
+    fn default() {
+        assert_eq!(
+            TreeView::<Action>::default(),
+            TreeView {
+                nodes: Vec::new(),
+                selected: None,
+                expand_handler: OptionalHandler::None,
+                collapse_handler: OptionalHandler::None,
+                select_handler: OptionalHandler::None,
+                style: Style::new(),
+                disabled: false,
+            }
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone(tree: TreeView<Action>) {
+            assert_eq!(tree.clone(), tree);
+        }
+
+        #[test]
+        fn hash(x: TreeView<Action>, y: TreeView<Action>) {
+            let mut a = DefaultHasher::new();
+            x.hash(&mut a);
+
+            let mut b = DefaultHasher::new();
+            y.hash(&mut b);
+
+            assert_eq!(x == y, a.finish() == b.finish());
+        }
+
+        #[test]
+        fn visible_nodes_have_expanded_ancestors(tree: TreeView<Action>) {
+            let visible = tree.visible();
+
+            for path in &visible {
+                for depth in 1..path.len() {
+                    assert!(tree.get(path[..depth].iter().copied()).unwrap().expanded);
+                }
+
+                let node = tree.get(path.iter().copied()).unwrap();
+                let shown = (0..node.children.len()).all(|i| visible.contains(&[&path[..], &[i]].concat()));
+                assert_eq!(shown, node.expanded || node.children.is_empty());
+            }
+
+            assert_eq!(visible.iter().filter(|p| p.len() == 1).count(), tree.nodes.len());
+        }
+
+        #[test]
+        fn toggling_inverts_the_expanded_flag(mut tree: TreeView<Action>, path in vec(0..4usize, 0..4)) {
+            let expected = |expanded| match expanded {
+                true => Event::from(Collapsed { path: path.clone() }),
+                false => Event::from(Expanded { path: path.clone() }),
+            };
+
+            let node = tree.get(path.iter().copied()).map(|n| n.expanded);
+            assert_eq!(tree.toggle(path.clone()), node.map(expected));
+
+            if let Some(node) = tree.get_mut(path.iter().copied()) {
+                node.expanded = !node.expanded;
+                let expanded = node.expanded;
+                assert_eq!(tree.toggle(path.clone()), Some(expected(expanded)));
+            }
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use crate::{event::Scrolled, style::Style, widget::Widget, Variant};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+/// The semantic representation of a vertical list of uniform rows of which only those within
+/// the viewport are built.
+///
+/// Rows are built through `row`, which maps the logical index of an item to its widget, whenever
+/// the scroll offset or the size of the viewport change.
+/// Paths address rows by their logical index, such that `[i]` refers to the `i`-th item
+/// regardless of how many rows precede it in [`Widget::children`], see [`Widget::indices`].
+///
+/// ## Example
+/// ```rust
+/// use oxidizer::widget::{Button, VirtualList, Widget};
+///
+/// let row: fn(usize) -> Widget<'static, ()> = |i| Button { label: i.to_string(), ..Default::default() }.into();
+/// let list: Widget<()> = VirtualList::new(100_000, 20, row).viewport(100).scroll(2000).into();
+///
+/// assert_eq!(list.indices(), 100..105);
+/// assert_eq!(list[[102usize]], row(102));
+/// assert_eq!(list.get([7usize]), None);
+/// ```
+#[derive(derivative::Derivative)]
+#[derivative(
+    Debug(bound = ""),
+    Clone(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = "")
+)]
+pub struct VirtualList<'w, A> {
+    len: usize,
+    extent: u32,
+    offset: u32,
+    viewport: u32,
+    #[derivative(PartialEq(compare_with = "eq_row"))]
+    row: fn(usize) -> Widget<'w, A>,
+    visible: Range<usize>,
+    pub(crate) rows: Box<[Widget<'w, A>]>,
+    #[derivative(PartialEq(compare_with = "eq_handler"))]
+    pub handler: Option<fn(&VirtualList<A>, &Scrolled) -> A>,
+    pub style: Style,
+    /// Whether events are refused by every row.
+    pub disabled: bool,
+}
+
+impl<'w, A> Variant<Widget<'w, A>> for VirtualList<'w, A> {}
+
+impl<'w, A> VirtualList<'w, A> {
+    /// A list of `len` rows that are `extent` logical pixels high.
+    ///
+    /// Until the size of the viewport is known, only the row at the scroll offset is built.
+    pub fn new(len: usize, extent: u32, row: fn(usize) -> Widget<'w, A>) -> Self {
+        VirtualList {
+            len,
+            extent,
+            offset: 0,
+            viewport: 0,
+            row,
+            visible: 0..0,
+            rows: Box::new([]),
+            handler: None,
+            style: Style::new(),
+            disabled: false,
+        }
+        .build()
+    }
+
+    /// Sets the vertical scroll position in logical pixels and builds the rows now visible.
+    pub fn scroll(self, offset: u32) -> Self {
+        VirtualList { offset, ..self }.build()
+    }
+
+    /// Sets the height of the visible area in logical pixels and builds the rows now visible.
+    pub fn viewport(self, viewport: u32) -> Self {
+        VirtualList { viewport, ..self }.build()
+    }
+
+    /// The number of items in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The height of every row in logical pixels.
+    pub fn extent(&self) -> u32 {
+        self.extent
+    }
+
+    /// The vertical scroll position in logical pixels.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// The range of logical indices of the rows that are built.
+    pub fn visible(&self) -> Range<usize> {
+        self.visible.clone()
+    }
+
+    fn build(self) -> Self {
+        let visible = self.range();
+
+        if visible == self.visible {
+            return self;
+        }
+
+        let rows = visible.clone().map(self.row).collect();
+        VirtualList {
+            visible,
+            rows,
+            ..self
+        }
+    }
+
+    fn range(&self) -> Range<usize> {
+        let extent = self.extent.max(1);
+        let start = ((self.offset / extent) as usize).min(self.len);
+        let end = match self.viewport {
+            0 => start + 1,
+            h => {
+                let bottom = self.offset.saturating_add(h);
+                (bottom / extent + u32::from(bottom % extent != 0)) as usize
+            }
+        };
+
+        start..end.min(self.len)
+    }
+
+    /// Compares everything but the rows, which are built from the rest.
+    pub(crate) fn props(&self) -> impl Eq + Hash + '_ {
+        (
+            (self.len, self.extent, self.offset, self.viewport),
+            self.row as *const (),
+            &self.visible,
+            self.handler.map(|h| h as *const ()),
+            &self.style,
+            self.disabled,
+        )
+    }
+}
+
+fn eq_row<'w, A>(a: &fn(usize) -> Widget<'w, A>, b: &fn(usize) -> Widget<'w, A>) -> bool {
+    *a as *const () == *b as *const ()
+}
+
+type ListHandler<A> = Option<fn(&VirtualList<A>, &Scrolled) -> A>;
+
+fn eq_handler<A>(a: &ListHandler<A>, b: &ListHandler<A>) -> bool {
+    a.map(|h| h as *const ()) == b.map(|h| h as *const ())
+}
+
+impl<'w, A> Hash for VirtualList<'w, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.props().hash(state);
+    }
+}
+
+use std::slice::Iter;
+
+impl<'a, 'w: 'a, A> IntoIterator for &'a VirtualList<'w, A> {
+    type Item = &'a Widget<'w, A>;
+    type IntoIter = Iter<'a, Widget<'w, A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.iter()
+    }
+}
+
+use std::ops::Deref;
+
+impl<'w, A> Deref for VirtualList<'w, A> {
+    type Target = [Widget<'w, A>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.rows
+    }
+}
+
+#[cfg(test)]
+use super::{Button, Text};
+
+#[cfg(test)]
+use proptest::{arbitrary::Arbitrary, prelude::*};
+
+#[cfg(test)]
+impl<A: 'static + Default> Arbitrary for VirtualList<'static, A> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let rows: [fn(usize) -> Widget<'static, A>; 2] = [
+            |i| {
+                Button {
+                    label: i.to_string(),
+                    ..Default::default()
+                }
+                .into()
+            },
+            |i| {
+                Text {
+                    content: i.to_string(),
+                    ..Default::default()
+                }
+                .into()
+            },
+        ];
+
+        (
+            0..1000usize,
+            0..=32u32,
+            any::<u16>(),
+            any::<u16>(),
+            0..rows.len(),
+            any::<bool>(),
+            any::<Style>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                move |(len, extent, offset, viewport, row, handled, style, disabled)| {
+                    let handler: fn(&VirtualList<A>, &Scrolled) -> A = |_, _| A::default();
+
+                    VirtualList {
+                        handler: handled.then_some(handler),
+                        style,
+                        disabled,
+                        ..VirtualList::new(len, extent, rows[row])
+                            .viewport(viewport.into())
+                            .scroll(offset.into())
+                    }
+                },
+            )
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    #[derive(Default)]
+    struct Action;
+
+    fn row(i: usize) -> Widget<'static, Action> {
+        Text {
+            content: i.to_string(),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    #[test]
+    fn new() {
+        let list = VirtualList::new(10, 20, row);
+
+        assert_eq!(list.len(), 10);
+        assert_eq!(list.offset(), 0);
+        assert_eq!(list.visible(), 0..1);
+        assert_eq!(&*list, &[row(0)]);
+        assert!(list.handler.is_none());
+        assert_eq!(list.style, Style::new());
+        assert!(!list.disabled);
+    }
+
+    #[test]
+    fn empty() {
+        let list = VirtualList::new(0, 20, row).viewport(100).scroll(40);
+        assert!(list.is_empty());
+        assert_eq!(list.visible(), 0..0);
+    }
+
+    proptest! {
+        #[test]
+        fn clone(list: VirtualList<'static, Action>) {
+            assert_eq!(list.clone(), list);
+        }
+
+        #[test]
+        fn hash(x: VirtualList<'static, Action>, y: VirtualList<'static, Action>) {
+            let mut a = DefaultHasher::new();
+            x.hash(&mut a);
+
+            let mut b = DefaultHasher::new();
+            y.hash(&mut b);
+
+            assert_eq!(x == y, a.finish() == b.finish());
+        }
+
+        #[test]
+        fn only_rows_within_the_viewport_are_built(len in 0..1000usize, extent in 1..=32u32, offset: u16, viewport in 1..=500u32) {
+            let list = VirtualList::new(len, extent, row).viewport(viewport).scroll(offset.into());
+            let (top, bottom) = (u32::from(offset), u32::from(offset) + viewport);
+
+            for i in 0..len {
+                let overlaps = (i as u32) * extent < bottom && (i as u32 + 1) * extent > top;
+                assert_eq!(list.visible().contains(&i), overlaps);
+            }
+
+            assert_eq!(list.rows.to_vec(), list.visible().map(row).collect::<Vec<_>>());
+        }
+    }
+}